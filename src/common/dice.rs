@@ -16,3 +16,10 @@ pub fn roll() -> [usize; 6] {
     });
     array
 }
+
+pub fn roll_die(sides: usize) -> usize {
+    if sides == 0 {
+        return 0
+    }
+    thread_rng().gen_range(1..=sides)
+}
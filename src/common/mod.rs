@@ -2,30 +2,17 @@ pub mod profeciency;
 pub mod dice;
 
 use std::fmt::Debug;
-use profeciency::*;
 
 /* ---------
    | Trait |
    --------- */
 pub trait Race {
-    fn as_string(&self) -> String;
+    /// Race data of an edition
+    type Stat;
 
-    fn get_stat(&self) -> Stat;
-}
+    fn as_string(&self) -> String;
 
-/* ----------
-   | Struct |
-   ---------- */
-#[derive(PartialEq, Eq, Debug)]
-pub struct Stat {
-    pub ap: [usize; 7],
-    pub lang_point: usize,
-    pub lang: Vec<Language>,
-    pub weap: Vec<Weapon>,
-    pub armor: Vec<Armor>,
-    pub skill: Vec<Skill>,
-    pub speed: usize,
-    pub size: Size
+    fn get_stat(&self) -> Self::Stat;
 }
 
 /* --------
@@ -43,13 +30,17 @@ impl Edition {
     }
 }
 
-#[derive(Hash, Eq, PartialEq, Debug)]
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
 pub enum AP {
     STR, DEX, CON,
     INT, WIS, CHA
 }
 
 impl AP {
+    pub fn get_all() -> [AP; 6] {
+        [Self::STR, Self::DEX, Self::CON, Self::INT, Self::WIS, Self::CHA]
+    }
+
     pub fn get_index(&self) -> usize {
         match self {
             Self::STR => 0,
@@ -67,6 +58,20 @@ pub enum Size {
     Unknown, Tiny, Small, Medium, Large
 }
 
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum DamageType {
+    Acid, Bludgeoning, Cold, Fire, Force,
+    Lightning, Necrotic, Piercing, Poison, Psychic,
+    Radiant, Slashing, Thunder
+}
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum Condition {
+    Blinded, Charmed, Deafened, Frightened, Grappled,
+    Incapacitated, Invisible, Paralyzed, Petrified, Poisoned,
+    Prone, Restrained, Stunned, Unconscious
+}
+
 /* -------------
    | Functions |
    ------------- */
@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use super::AP;
 /* ---------
    | Macro |
    --------- */
@@ -11,6 +12,13 @@ macro_rules! define_enum {
             )*
         }
 
+        impl $name {
+            /// Return every variant in declaration order
+            pub fn get_all() -> Vec<Self> {
+                vec![$(Self::$item,)*]
+            }
+        }
+
         impl Profeciency for $name {
             fn as_string(&self) -> String {
                 match self {
@@ -44,8 +52,11 @@ pub trait Profeciency {
    | Profeciency |
    --------------- */
 define_enum!(Language {
-    Common, Elven, Aquan, Dwarven,
-    Undercommon
+    Common, Dwarven, Elven, Giant, Gnomish,
+    Goblin, Halfling, Orc, Abyssal, Celestial,
+    Draconic, DeepSpeech, Infernal, Primordial,
+    Aquan, Auran, Ignan, Terran, Sylvan,
+    Undercommon, Druidic, ThievesCant
 });
 
 define_enum!(Weapon {
@@ -67,10 +78,38 @@ define_enum!(Skill {
     Persuasion
 });
 
+impl Skill {
+    /// Return the ability governing the skill
+    pub fn get_ability(&self) -> AP {
+        match self {
+            Self::Athletics => AP::STR,
+            Self::Acrobatics | Self::SleightOfHand
+                | Self::Stealth => AP::DEX,
+            Self::Arcana | Self::History | Self::Investigation
+                | Self::Nature | Self::Religion => AP::INT,
+            Self::AnimalHandling | Self::Insight | Self::Medicine
+                | Self::Perception | Self::Survial => AP::WIS,
+            Self::Deception | Self::Intimidation
+                | Self::Performance | Self::Persuasion => AP::CHA,
+        }
+    }
+}
+
 define_enum!(Armor {
     Light, Medium, Heavy, Shield
 });
 
 define_enum!(Tools {
-    TheivesTools, TinkersTools
+    AlchemistsSupplies, BrewersSupplies, CalligraphersSupplies,
+    CarpentersTools, CartographersTools, CobblersTools,
+    CooksUtensils, GlassblowersTools, JewelersTools,
+    LeatherworkersTools, MasonsTools, PaintersSupplies,
+    PottersTools, SmithsTools, TinkersTools,
+    WeaversTools, WoodcarversTools,
+    DiceSet, DragonchessSet, PlayingCardSet, ThreeDragonAnteSet,
+    Bagpipes, Drum, Dulcimer, Flute, Lute,
+    Lyre, Horn, PanFlute, Shawm, Viol,
+    DisguiseKit, ForgeryKit, HerbalismKit,
+    NavigatorsTools, PoisonersKit, TheivesTools,
+    VehiclesLand, VehiclesWater
 });
@@ -0,0 +1,413 @@
+use crate::common::profeciency::{Tools, Weapon, Skill::{self, *}};
+use super::tool::{ToolCategory, ToolChoice};
+use super::equipment::{Gear, Item};
+use super::error::CharacterError;
+
+/* ---------
+   | Macro |
+   --------- */
+macro_rules! new_background {
+    ($($name:ident {
+        skill: $skill:expr,
+        tools: $tools:expr,
+        tool_choice: $tool_choice:expr,
+        lang_point: $lang_point:expr,
+        equipment: $equipment:expr,
+        feature: $feature:expr
+    })*) => {
+        #[derive(PartialEq, Eq, Debug, Clone)]
+        pub enum Background {
+            $($name,)*
+            Custom(CustomBackground)
+        }
+
+        impl Background {
+            /// Return background name as String
+            pub fn as_string(&self) -> String {
+                match self {
+                    $(
+                        Self::$name => stringify!($name).to_string(),
+                    )*
+                    Self::Custom(_) => "Custom".to_string()
+                }
+            }
+
+            /// Return proficiencies, choices, equipment
+            /// and feature granted by background
+            pub fn get_stat(&self) -> BackgroundStat {
+                match self {
+                    $(
+                        Self::$name => BackgroundStat {
+                            skill: $skill,
+                            tools: $tools,
+                            tool_choice: $tool_choice,
+                            lang_point: $lang_point,
+                            equipment: $equipment,
+                            feature: $feature,
+                        },
+                    )*
+                    Self::Custom(custom) => BackgroundStat {
+                        skill: custom.skill.clone(),
+                        tools: custom.tools.clone(),
+                        tool_choice: vec![],
+                        lang_point: custom.lang_point,
+                        equipment: custom.feature.get_stat().equipment,
+                        feature: custom.feature.get_stat().feature,
+                    }
+                }
+            }
+        }
+    };
+}
+
+/* ----------
+   | Struct |
+   ---------- */
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BackgroundStat {
+    pub skill: Vec<Skill>,
+    pub tools: Vec<Tools>,
+    pub tool_choice: Vec<ToolChoice>,
+    pub lang_point: usize,
+    /// Items with their quantity, tools picked from
+    /// `tool_choice` are not included
+    pub equipment: Vec<(Item, usize)>,
+    pub feature: Feature
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Feature {
+    pub name: &'static str,
+    pub description: &'static str
+}
+
+/// Custom background following PHB rules,
+/// two skills, a total of two tool proficiencies
+/// or languages, and a feature (with equipment)
+/// taken from another background
+///
+/// Example:
+/// ```
+/// use role4me_lib::ed_5;
+/// use role4me_lib::prelude::*;
+///
+/// let background = ed_5::CustomBackground::build()
+///     .skill(Skill::Arcana)
+///     .skill(Skill::Stealth)
+///     .tool(Tools::TheivesTools)
+///     .lang()
+///     .feature(ed_5::Background::Sage)
+///     .finish();
+///
+/// assert!(background.is_ok());
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CustomBackground {
+    skill: Vec<Skill>,
+    tools: Vec<Tools>,
+    lang_point: usize,
+    feature: Box<Background>
+}
+
+impl CustomBackground {
+    /// Start an empty custom background
+    pub fn build() -> CustomBackground {
+        CustomBackground {
+            skill: Vec::new(),
+            tools: Vec::new(),
+            lang_point: 0,
+            feature: Box::new(Background::Unknown)
+        }
+    }
+
+    /// Add a skill proficiency
+    pub fn skill(mut self, skill: Skill) -> Self {
+        if !self.skill.contains(&skill) {
+            self.skill.push(skill);
+        }
+        self
+    }
+
+    /// Add a tool proficiency
+    pub fn tool(mut self, tool: Tools) -> Self {
+        if !self.tools.contains(&tool) {
+            self.tools.push(tool);
+        }
+        self
+    }
+
+    /// Add a language of choice
+    pub fn lang(mut self) -> Self {
+        self.lang_point += 1;
+        self
+    }
+
+    /// Take feature and equipment of another background
+    pub fn feature(mut self, background: Background) -> Self {
+        if let Background::Custom(custom) = background {
+            self.feature = custom.feature;
+        }
+        else {
+            self.feature = Box::new(background);
+        }
+        self
+    }
+
+    /// Validate custom background against PHB rules
+    pub fn finish(self) -> Result<Background, CharacterError> {
+        if self.skill.len() != 2 {
+            return Err(CharacterError::CustomBackgroundSkill(self.skill.len()));
+        }
+        let proficiency = self.tools.len() + self.lang_point;
+        if proficiency != 2 {
+            return Err(CharacterError::CustomBackgroundProficiency(proficiency));
+        }
+        if *self.feature == Background::Unknown {
+            return Err(CharacterError::CustomBackgroundFeature);
+        }
+        Ok(Background::Custom(self))
+    }
+}
+
+/* ---------------
+   | Backgrounds |
+   --------------- */
+new_background!(
+    Acolyte {
+        skill: vec![Insight, Religion],
+        tools: vec![],
+        tool_choice: vec![],
+        lang_point: 2,
+        equipment: vec![
+            (Item::Gear(Gear::HolySymbol), 1),
+            (Item::Gear(Gear::Book), 1),
+            (Item::Gear(Gear::Incense), 5),
+            (Item::Gear(Gear::ClothesVestments), 1),
+            (Item::Gear(Gear::ClothesCommon), 1),
+            (Item::Gear(Gear::Pouch), 1),
+            (Item::Gear(Gear::GoldPiece), 15)
+        ],
+        feature: Feature {
+            name: "Shelter of the Faithful",
+            description: "You and your companions can receive free healing and care at a temple, shrine or other established presence of your faith."
+        }
+    }
+    Charlatan {
+        skill: vec![Deception, SleightOfHand],
+        tools: vec![Tools::DisguiseKit, Tools::ForgeryKit],
+        tool_choice: vec![],
+        lang_point: 0,
+        equipment: vec![
+            (Item::Gear(Gear::ClothesFine), 1),
+            (Item::Tool(Tools::DisguiseKit), 1),
+            (Item::Gear(Gear::ConTools), 1),
+            (Item::Gear(Gear::Pouch), 1),
+            (Item::Gear(Gear::GoldPiece), 15)
+        ],
+        feature: Feature {
+            name: "False Identity",
+            description: "You have created a second identity that includes documentation, established acquaintances and disguises."
+        }
+    }
+    Criminal {
+        skill: vec![Deception, Stealth],
+        tools: vec![Tools::TheivesTools],
+        tool_choice: vec![ToolChoice::Category(ToolCategory::Gaming)],
+        lang_point: 0,
+        equipment: vec![
+            (Item::Gear(Gear::Crowbar), 1),
+            (Item::Gear(Gear::ClothesCommon), 1),
+            (Item::Gear(Gear::Pouch), 1),
+            (Item::Gear(Gear::GoldPiece), 15)
+        ],
+        feature: Feature {
+            name: "Criminal Contact",
+            description: "You have a reliable and trustworthy contact who acts as your liaison to a network of other criminals."
+        }
+    }
+    Entertainer {
+        skill: vec![Acrobatics, Performance],
+        tools: vec![Tools::DisguiseKit],
+        tool_choice: vec![ToolChoice::Category(ToolCategory::Musical)],
+        lang_point: 0,
+        equipment: vec![
+            (Item::Gear(Gear::FavorOfAdmirer), 1),
+            (Item::Gear(Gear::ClothesCostume), 1),
+            (Item::Gear(Gear::Pouch), 1),
+            (Item::Gear(Gear::GoldPiece), 15)
+        ],
+        feature: Feature {
+            name: "By Popular Demand",
+            description: "You can always find a place to perform, and receive free lodging and food in exchange."
+        }
+    }
+    FolkHero {
+        skill: vec![AnimalHandling, Survial],
+        tools: vec![Tools::VehiclesLand],
+        tool_choice: vec![ToolChoice::Category(ToolCategory::Artisan)],
+        lang_point: 0,
+        equipment: vec![
+            (Item::Gear(Gear::Shovel), 1),
+            (Item::Gear(Gear::IronPot), 1),
+            (Item::Gear(Gear::ClothesCommon), 1),
+            (Item::Gear(Gear::Pouch), 1),
+            (Item::Gear(Gear::GoldPiece), 10)
+        ],
+        feature: Feature {
+            name: "Rustic Hospitality",
+            description: "Common folk will shelter you and hide you from the law or anyone else searching for you."
+        }
+    }
+    GuildArtisan {
+        skill: vec![Insight, Persuasion],
+        tools: vec![],
+        tool_choice: vec![ToolChoice::Category(ToolCategory::Artisan)],
+        lang_point: 1,
+        equipment: vec![
+            (Item::Gear(Gear::Letter), 1),
+            (Item::Gear(Gear::ClothesTravelers), 1),
+            (Item::Gear(Gear::Pouch), 1),
+            (Item::Gear(Gear::GoldPiece), 15)
+        ],
+        feature: Feature {
+            name: "Guild Membership",
+            description: "Your guild offers lodging and food if necessary, and pays for your funeral if needed."
+        }
+    }
+    Hermit {
+        skill: vec![Medicine, Religion],
+        tools: vec![Tools::HerbalismKit],
+        tool_choice: vec![],
+        lang_point: 1,
+        equipment: vec![
+            (Item::Gear(Gear::CaseScroll), 1),
+            (Item::Gear(Gear::Blanket), 1),
+            (Item::Gear(Gear::ClothesCommon), 1),
+            (Item::Tool(Tools::HerbalismKit), 1),
+            (Item::Gear(Gear::GoldPiece), 5)
+        ],
+        feature: Feature {
+            name: "Discovery",
+            description: "The quiet seclusion of your hermitage gave you access to a unique and powerful discovery."
+        }
+    }
+    Noble {
+        skill: vec![History, Persuasion],
+        tools: vec![],
+        tool_choice: vec![ToolChoice::Category(ToolCategory::Gaming)],
+        lang_point: 1,
+        equipment: vec![
+            (Item::Gear(Gear::ClothesFine), 1),
+            (Item::Gear(Gear::SignetRing), 1),
+            (Item::Gear(Gear::ScrollOfPedigree), 1),
+            (Item::Gear(Gear::Pouch), 1),
+            (Item::Gear(Gear::GoldPiece), 25)
+        ],
+        feature: Feature {
+            name: "Position of Privilege",
+            description: "People are inclined to think the best of you, and you are welcome in high society."
+        }
+    }
+    Outlander {
+        skill: vec![Athletics, Survial],
+        tools: vec![],
+        tool_choice: vec![ToolChoice::Category(ToolCategory::Musical)],
+        lang_point: 1,
+        equipment: vec![
+            (Item::Weapon(Weapon::Quaterstaff), 1),
+            (Item::Gear(Gear::HuntingTrap), 1),
+            (Item::Gear(Gear::Trophy), 1),
+            (Item::Gear(Gear::ClothesTravelers), 1),
+            (Item::Gear(Gear::Pouch), 1),
+            (Item::Gear(Gear::GoldPiece), 10)
+        ],
+        feature: Feature {
+            name: "Wanderer",
+            description: "You have an excellent memory for maps and geography, and can find food and fresh water for yourself and up to five other people each day."
+        }
+    }
+    Sage {
+        skill: vec![Arcana, History],
+        tools: vec![],
+        tool_choice: vec![],
+        lang_point: 2,
+        equipment: vec![
+            (Item::Gear(Gear::Ink), 1),
+            (Item::Gear(Gear::Quill), 1),
+            (Item::Gear(Gear::SmallKnife), 1),
+            (Item::Gear(Gear::Letter), 1),
+            (Item::Gear(Gear::ClothesCommon), 1),
+            (Item::Gear(Gear::Pouch), 1),
+            (Item::Gear(Gear::GoldPiece), 10)
+        ],
+        feature: Feature {
+            name: "Researcher",
+            description: "When you don't know a piece of lore, you often know where and from whom you can obtain it."
+        }
+    }
+    Sailor {
+        skill: vec![Athletics, Perception],
+        tools: vec![Tools::NavigatorsTools, Tools::VehiclesWater],
+        tool_choice: vec![],
+        lang_point: 0,
+        equipment: vec![
+            (Item::Weapon(Weapon::Club), 1),
+            (Item::Gear(Gear::RopeSilk), 1),
+            (Item::Gear(Gear::LuckyCharm), 1),
+            (Item::Gear(Gear::ClothesCommon), 1),
+            (Item::Gear(Gear::Pouch), 1),
+            (Item::Gear(Gear::GoldPiece), 10)
+        ],
+        feature: Feature {
+            name: "Ship's Passage",
+            description: "You can secure free passage on a sailing ship for yourself and your adventuring companions."
+        }
+    }
+    Soldier {
+        skill: vec![Athletics, Intimidation],
+        tools: vec![Tools::VehiclesLand],
+        tool_choice: vec![ToolChoice::Category(ToolCategory::Gaming)],
+        lang_point: 0,
+        equipment: vec![
+            (Item::Gear(Gear::Insignia), 1),
+            (Item::Gear(Gear::Trophy), 1),
+            (Item::Gear(Gear::ClothesCommon), 1),
+            (Item::Gear(Gear::Pouch), 1),
+            (Item::Gear(Gear::GoldPiece), 10)
+        ],
+        feature: Feature {
+            name: "Military Rank",
+            description: "Soldiers loyal to your former military organization still recognize your authority and influence."
+        }
+    }
+    Urchin {
+        skill: vec![SleightOfHand, Stealth],
+        tools: vec![Tools::DisguiseKit, Tools::TheivesTools],
+        tool_choice: vec![],
+        lang_point: 0,
+        equipment: vec![
+            (Item::Gear(Gear::SmallKnife), 1),
+            (Item::Gear(Gear::Map), 1),
+            (Item::Gear(Gear::PetMouse), 1),
+            (Item::Gear(Gear::Token), 1),
+            (Item::Gear(Gear::ClothesCommon), 1),
+            (Item::Gear(Gear::Pouch), 1),
+            (Item::Gear(Gear::GoldPiece), 10)
+        ],
+        feature: Feature {
+            name: "City Secrets",
+            description: "You know the secret patterns and flow to cities and can find passages through the urban sprawl."
+        }
+    }
+    Unknown {
+        skill: vec![],
+        tools: vec![],
+        tool_choice: vec![],
+        lang_point: 0,
+        equipment: vec![],
+        feature: Feature {
+            name: "",
+            description: ""
+        }
+    }
+);
@@ -1,4 +1,6 @@
-use crate::common::profeciency::{Tools, Armor, Weapon::{self, *}, Skill::{self, *}};
+use crate::common::profeciency::{Tools, Armor, Language, Weapon::*, Skill::{self, *}};
+use super::tool::{ToolCategory, ToolChoice};
+use super::equipment::{WeaponProf::{self, Category, Single}, WeaponCategory::*};
 use crate::common::AP;
 
 /* ---------
@@ -9,17 +11,29 @@ macro_rules! create_class {
         armor: $armor:expr,
         weapon: $weapon:expr,
         tools: $tools:expr,
+        tool_choice: $tool_choice:expr,
+        lang: $lang:expr,
         saving_throws: $saving_throws:expr,
         skill: $skill:expr,
-        prof_point: $prof_point:expr
+        prof_point: $prof_point:expr,
+        hit_die: $hit_die:expr,
+        asi_level: $asi_level:expr,
+        spellcasting_level: $spellcasting_level:expr,
+        multiclass_req: $multiclass_req:expr,
+        multiclass_armor: $multiclass_armor:expr,
+        multiclass_weapon: $multiclass_weapon:expr,
+        multiclass_tools: $multiclass_tools:expr,
+        multiclass_tool_choice: $multiclass_tool_choice:expr,
+        multiclass_prof_point: $multiclass_prof_point:expr
     }),*) => {
-        #[derive(Eq, PartialEq, Debug)]
+        #[derive(Eq, PartialEq, Hash, Debug, Clone, Copy)]
         pub enum Class {
             $($class,)*
         }
 
         impl Class {
-            fn get_armor_prof(&self) -> Vec<Armor> {
+            /// Return armor proficiencies granted by class
+            pub fn get_armor_prof(&self) -> Vec<Armor> {
                 match self {
                     $(
                         Class::$class => $armor,
@@ -27,7 +41,8 @@ macro_rules! create_class {
                 }
             }
 
-            fn get_weapon_prof(&self) -> Vec<Weapon> {
+            /// Return weapon proficiencies granted by class
+            pub fn get_weapon_prof(&self) -> Vec<WeaponProf> {
                 match self {
                     $(
                         Class::$class => $weapon,
@@ -35,7 +50,8 @@ macro_rules! create_class {
                 }
             }
 
-            fn get_tools_prof(&self) -> Vec<Tools> {
+            /// Return tool proficiencies granted by class
+            pub fn get_tools_prof(&self) -> Vec<Tools> {
                 match self {
                     $(
                         Class::$class => $tools,
@@ -43,7 +59,26 @@ macro_rules! create_class {
                 }
             }
 
-            fn get_saving_throw_prof(&self) -> Vec<AP> {
+            /// Return tool proficiencies to choose from class
+            pub fn get_tool_choice(&self) -> Vec<ToolChoice> {
+                match self {
+                    $(
+                        Class::$class => $tool_choice,
+                    )*
+                }
+            }
+
+            /// Return secret languages taught by class
+            pub fn get_lang(&self) -> Vec<Language> {
+                match self {
+                    $(
+                        Class::$class => $lang,
+                    )*
+                }
+            }
+
+            /// Return saving throw proficiencies granted by class
+            pub fn get_saving_throw_prof(&self) -> Vec<AP> {
                 match self {
                     $(
                         Class::$class => $saving_throws,
@@ -51,7 +86,8 @@ macro_rules! create_class {
                 }
             }
 
-            fn get_skill(&self) -> Vec<Skill> {
+            /// Return skills available to choose from
+            pub fn get_skill(&self) -> Vec<Skill> {
                 match self {
                     $(
                         Class::$class => $skill,
@@ -59,13 +95,103 @@ macro_rules! create_class {
                 }
             }
 
-            fn get_prof_point(&self) -> usize {
+            /// Return number of skills to choose
+            pub fn get_prof_point(&self) -> usize {
                 match self {
                     $(
                         Class::$class => $prof_point,
                     )*
                 }
             }
+
+            /// Return number of sides of class hit die
+            pub fn get_hit_die(&self) -> usize {
+                match self {
+                    $(
+                        Class::$class => $hit_die,
+                    )*
+                }
+            }
+
+            /// Return class levels granting an ability score
+            /// improvement (or a feat instead)
+            pub fn get_asi_level(&self) -> Vec<usize> {
+                match self {
+                    $(
+                        Class::$class => $asi_level,
+                    )*
+                }
+            }
+
+            /// Return class level from which spells can be
+            /// cast, None when class has no spellcasting
+            pub fn get_spellcasting_level(&self) -> Option<usize> {
+                match self {
+                    $(
+                        Class::$class => $spellcasting_level,
+                    )*
+                }
+            }
+
+            /// Return minimum ability scores to multiclass
+            /// Any one of the inner lists must be fully met
+            pub fn get_multiclass_req(&self) -> Vec<Vec<(AP, usize)>> {
+                match self {
+                    $(
+                        Class::$class => $multiclass_req,
+                    )*
+                }
+            }
+
+            /// Return armor proficiencies granted when
+            /// multiclassing into class
+            pub fn get_multiclass_armor_prof(&self) -> Vec<Armor> {
+                match self {
+                    $(
+                        Class::$class => $multiclass_armor,
+                    )*
+                }
+            }
+
+            /// Return weapon proficiencies granted when
+            /// multiclassing into class
+            pub fn get_multiclass_weapon_prof(&self) -> Vec<WeaponProf> {
+                match self {
+                    $(
+                        Class::$class => $multiclass_weapon,
+                    )*
+                }
+            }
+
+            /// Return tool proficiencies granted when
+            /// multiclassing into class
+            pub fn get_multiclass_tools_prof(&self) -> Vec<Tools> {
+                match self {
+                    $(
+                        Class::$class => $multiclass_tools,
+                    )*
+                }
+            }
+
+            /// Return tool proficiencies to choose when
+            /// multiclassing into class
+            pub fn get_multiclass_tool_choice(&self) -> Vec<ToolChoice> {
+                match self {
+                    $(
+                        Class::$class => $multiclass_tool_choice,
+                    )*
+                }
+            }
+
+            /// Return number of skills to choose when
+            /// multiclassing into class
+            pub fn get_multiclass_prof_point(&self) -> usize {
+                match self {
+                    $(
+                        Class::$class => $multiclass_prof_point,
+                    )*
+                }
+            }
         }
     };
 }
@@ -73,62 +199,318 @@ macro_rules! create_class {
 /* -----------
    | Classes |
    ----------- */
-// Simple Weapons: 
-//     [
-//         Club, Dagger, Greatclub, Handaxe, Javelin,
-//         LightHammer, Mace, Quaterstaff, Sickle, Spear, 
-//         LightCrossbow, Dart, Shortbow, Sling
-//     ]
-// Martial Weapons: 
-//     [
-//         Battleaxe, Flail, Glaive, Greataxe, Greatsword,
-//         Halberd, Lance, Longsword, Maul, Morningstar,
-//         Pike, Rapier, Scimitar, Shortsword, Trident,
-//         WarPick, Warhammer, Whip, Blowgun, HandCrossbow,
-//         HeavyCrossbow, Longbow, Net
-//     ]
-
 create_class!(
-    Cleric {
-        armor: vec![Armor::Light, Armor::Heavy, Armor::Shield],
+    Barbarian {
+        armor: vec![Armor::Light, Armor::Medium, Armor::Shield],
+        weapon: vec![Category(Simple), Category(Martial)],
+        tools: vec![],
+        tool_choice: vec![],
+        lang: vec![],
+        saving_throws: vec![AP::STR, AP::CON],
+        skill: vec![
+            AnimalHandling, Athletics, Intimidation,
+            Nature, Perception, Survial
+        ],
+        prof_point: 2,
+        hit_die: 12,
+        asi_level: vec![4, 8, 12, 16, 19],
+        spellcasting_level: None,
+        multiclass_req: vec![vec![(AP::STR, 13)]],
+        multiclass_armor: vec![Armor::Shield],
+        multiclass_weapon: vec![Category(Simple), Category(Martial)],
+        multiclass_tools: vec![],
+        multiclass_tool_choice: vec![],
+        multiclass_prof_point: 0
+    },
+
+    Bard {
+        armor: vec![Armor::Light],
         weapon: vec![
-            Club, Dagger, Greatclub, Handaxe, Javelin,
-            LightHammer, Mace, Quaterstaff, Sickle, Spear, 
-            LightCrossbow, Dart, Shortbow, Sling
+            Category(Simple), Single(HandCrossbow),
+            Single(Longsword), Single(Rapier), Single(Shortsword)
         ],
         tools: vec![],
+        tool_choice: vec![
+            ToolChoice::Category(ToolCategory::Musical),
+            ToolChoice::Category(ToolCategory::Musical),
+            ToolChoice::Category(ToolCategory::Musical)
+        ],
+        lang: vec![],
+        saving_throws: vec![AP::DEX, AP::CHA],
+        skill: Skill::get_all(),
+        prof_point: 3,
+        hit_die: 8,
+        asi_level: vec![4, 8, 12, 16, 19],
+        spellcasting_level: Some(1),
+        multiclass_req: vec![vec![(AP::CHA, 13)]],
+        multiclass_armor: vec![Armor::Light],
+        multiclass_weapon: vec![],
+        multiclass_tools: vec![],
+        multiclass_tool_choice: vec![ToolChoice::Category(ToolCategory::Musical)],
+        multiclass_prof_point: 1
+    },
+
+    Cleric {
+        armor: vec![Armor::Light, Armor::Medium, Armor::Shield],
+        weapon: vec![Category(Simple)],
+        tools: vec![],
+        tool_choice: vec![],
+        lang: vec![],
         saving_throws: vec![AP::WIS, AP::CHA],
         skill: vec![History, Insight, Medicine, Persuasion, Religion],
-        prof_point: 2
+        prof_point: 2,
+        hit_die: 8,
+        asi_level: vec![4, 8, 12, 16, 19],
+        spellcasting_level: Some(1),
+        multiclass_req: vec![vec![(AP::WIS, 13)]],
+        multiclass_armor: vec![Armor::Light, Armor::Medium, Armor::Shield],
+        multiclass_weapon: vec![],
+        multiclass_tools: vec![],
+        multiclass_tool_choice: vec![],
+        multiclass_prof_point: 0
     },
 
-    Ranger {
+    Druid {
         armor: vec![Armor::Light, Armor::Medium, Armor::Shield],
         weapon: vec![
-            Club, Dagger, Greatclub, Handaxe, Javelin,
-            LightHammer, Mace, Quaterstaff, Sickle, Spear, 
-            LightCrossbow, Dart, Shortbow, Sling,
-            Battleaxe, Flail, Glaive, Greataxe, Greatsword,
-            Halberd, Lance, Longsword, Maul, Morningstar,
-            Pike, Rapier, Scimitar, Shortsword, Trident,
-            WarPick, Warhammer, Whip, Blowgun, HandCrossbow,
-            HeavyCrossbow, Longbow, Net
+            Single(Club), Single(Dagger), Single(Dart), Single(Javelin),
+            Single(Mace), Single(Quaterstaff), Single(Scimitar),
+            Single(Sickle), Single(Sling), Single(Spear)
+        ],
+        tools: vec![Tools::HerbalismKit],
+        tool_choice: vec![],
+        lang: vec![Language::Druidic],
+        saving_throws: vec![AP::INT, AP::WIS],
+        skill: vec![
+            Arcana, AnimalHandling, Insight, Medicine,
+            Nature, Perception, Religion, Survial
+        ],
+        prof_point: 2,
+        hit_die: 8,
+        asi_level: vec![4, 8, 12, 16, 19],
+        spellcasting_level: Some(1),
+        multiclass_req: vec![vec![(AP::WIS, 13)]],
+        multiclass_armor: vec![Armor::Light, Armor::Medium, Armor::Shield],
+        multiclass_weapon: vec![],
+        multiclass_tools: vec![],
+        multiclass_tool_choice: vec![],
+        multiclass_prof_point: 0
+    },
+
+    Fighter {
+        armor: vec![Armor::Light, Armor::Medium, Armor::Heavy, Armor::Shield],
+        weapon: vec![Category(Simple), Category(Martial)],
+        tools: vec![],
+        tool_choice: vec![],
+        lang: vec![],
+        saving_throws: vec![AP::STR, AP::CON],
+        skill: vec![
+            Acrobatics, AnimalHandling, Athletics, History,
+            Insight, Intimidation, Perception, Survial
+        ],
+        prof_point: 2,
+        hit_die: 10,
+        asi_level: vec![4, 6, 8, 12, 14, 16, 19],
+        spellcasting_level: None,
+        multiclass_req: vec![vec![(AP::STR, 13)], vec![(AP::DEX, 13)]],
+        multiclass_armor: vec![Armor::Light, Armor::Medium, Armor::Shield],
+        multiclass_weapon: vec![Category(Simple), Category(Martial)],
+        multiclass_tools: vec![],
+        multiclass_tool_choice: vec![],
+        multiclass_prof_point: 0
+    },
+
+    Monk {
+        armor: vec![],
+        weapon: vec![Category(Simple), Single(Shortsword)],
+        tools: vec![],
+        tool_choice: vec![ToolChoice::Among([
+            ToolCategory::Artisan.get_tools(),
+            ToolCategory::Musical.get_tools()
+        ].concat())],
+        lang: vec![],
+        saving_throws: vec![AP::STR, AP::DEX],
+        skill: vec![
+            Acrobatics, Athletics, History,
+            Insight, Religion, Stealth
+        ],
+        prof_point: 2,
+        hit_die: 8,
+        asi_level: vec![4, 8, 12, 16, 19],
+        spellcasting_level: None,
+        multiclass_req: vec![vec![(AP::DEX, 13), (AP::WIS, 13)]],
+        multiclass_armor: vec![],
+        multiclass_weapon: vec![Category(Simple), Single(Shortsword)],
+        multiclass_tools: vec![],
+        multiclass_tool_choice: vec![],
+        multiclass_prof_point: 0
+    },
+
+    Paladin {
+        armor: vec![Armor::Light, Armor::Medium, Armor::Heavy, Armor::Shield],
+        weapon: vec![Category(Simple), Category(Martial)],
+        tools: vec![],
+        tool_choice: vec![],
+        lang: vec![],
+        saving_throws: vec![AP::WIS, AP::CHA],
+        skill: vec![
+            Athletics, Insight, Intimidation,
+            Medicine, Persuasion, Religion
         ],
+        prof_point: 2,
+        hit_die: 10,
+        asi_level: vec![4, 8, 12, 16, 19],
+        spellcasting_level: Some(2),
+        multiclass_req: vec![vec![(AP::STR, 13), (AP::CHA, 13)]],
+        multiclass_armor: vec![Armor::Light, Armor::Medium, Armor::Shield],
+        multiclass_weapon: vec![Category(Simple), Category(Martial)],
+        multiclass_tools: vec![],
+        multiclass_tool_choice: vec![],
+        multiclass_prof_point: 0
+    },
+
+    Ranger {
+        armor: vec![Armor::Light, Armor::Medium, Armor::Shield],
+        weapon: vec![Category(Simple), Category(Martial)],
         tools: vec![],
+        tool_choice: vec![],
+        lang: vec![],
         saving_throws: vec![AP::STR, AP::DEX],
         skill: vec![
             AnimalHandling, Athletics, Insight, Investigation,
             Nature, Perception, Stealth, Survial
         ],
-        prof_point: 3
+        prof_point: 3,
+        hit_die: 10,
+        asi_level: vec![4, 8, 12, 16, 19],
+        spellcasting_level: Some(2),
+        multiclass_req: vec![vec![(AP::DEX, 13), (AP::WIS, 13)]],
+        multiclass_armor: vec![Armor::Light, Armor::Medium, Armor::Shield],
+        multiclass_weapon: vec![Category(Simple), Category(Martial)],
+        multiclass_tools: vec![],
+        multiclass_tool_choice: vec![],
+        multiclass_prof_point: 1
+    },
+
+    Rogue {
+        armor: vec![Armor::Light],
+        weapon: vec![
+            Category(Simple), Single(HandCrossbow),
+            Single(Longsword), Single(Rapier), Single(Shortsword)
+        ],
+        tools: vec![Tools::TheivesTools],
+        tool_choice: vec![],
+        lang: vec![Language::ThievesCant],
+        saving_throws: vec![AP::DEX, AP::INT],
+        skill: vec![
+            Acrobatics, Athletics, Deception, Insight,
+            Intimidation, Investigation, Perception, Performance,
+            Persuasion, SleightOfHand, Stealth
+        ],
+        prof_point: 4,
+        hit_die: 8,
+        asi_level: vec![4, 8, 10, 12, 16, 19],
+        spellcasting_level: None,
+        multiclass_req: vec![vec![(AP::DEX, 13)]],
+        multiclass_armor: vec![Armor::Light],
+        multiclass_weapon: vec![],
+        multiclass_tools: vec![Tools::TheivesTools],
+        multiclass_tool_choice: vec![],
+        multiclass_prof_point: 1
+    },
+
+    Sorcerer {
+        armor: vec![],
+        weapon: vec![
+            Single(Dagger), Single(Dart), Single(Sling),
+            Single(Quaterstaff), Single(LightCrossbow)
+        ],
+        tools: vec![],
+        tool_choice: vec![],
+        lang: vec![],
+        saving_throws: vec![AP::CON, AP::CHA],
+        skill: vec![
+            Arcana, Deception, Insight,
+            Intimidation, Persuasion, Religion
+        ],
+        prof_point: 2,
+        hit_die: 6,
+        asi_level: vec![4, 8, 12, 16, 19],
+        spellcasting_level: Some(1),
+        multiclass_req: vec![vec![(AP::CHA, 13)]],
+        multiclass_armor: vec![],
+        multiclass_weapon: vec![],
+        multiclass_tools: vec![],
+        multiclass_tool_choice: vec![],
+        multiclass_prof_point: 0
+    },
+
+    Warlock {
+        armor: vec![Armor::Light],
+        weapon: vec![Category(Simple)],
+        tools: vec![],
+        tool_choice: vec![],
+        lang: vec![],
+        saving_throws: vec![AP::WIS, AP::CHA],
+        skill: vec![
+            Arcana, Deception, History, Intimidation,
+            Investigation, Nature, Religion
+        ],
+        prof_point: 2,
+        hit_die: 8,
+        asi_level: vec![4, 8, 12, 16, 19],
+        spellcasting_level: Some(1),
+        multiclass_req: vec![vec![(AP::CHA, 13)]],
+        multiclass_armor: vec![Armor::Light],
+        multiclass_weapon: vec![Category(Simple)],
+        multiclass_tools: vec![],
+        multiclass_tool_choice: vec![],
+        multiclass_prof_point: 0
+    },
+
+    Wizard {
+        armor: vec![],
+        weapon: vec![
+            Single(Dagger), Single(Dart), Single(Sling),
+            Single(Quaterstaff), Single(LightCrossbow)
+        ],
+        tools: vec![],
+        tool_choice: vec![],
+        lang: vec![],
+        saving_throws: vec![AP::INT, AP::WIS],
+        skill: vec![
+            Arcana, History, Insight,
+            Investigation, Medicine, Religion
+        ],
+        prof_point: 2,
+        hit_die: 6,
+        asi_level: vec![4, 8, 12, 16, 19],
+        spellcasting_level: Some(1),
+        multiclass_req: vec![vec![(AP::INT, 13)]],
+        multiclass_armor: vec![],
+        multiclass_weapon: vec![],
+        multiclass_tools: vec![],
+        multiclass_tool_choice: vec![],
+        multiclass_prof_point: 0
     },
 
     Unknown {
         armor: vec![],
         weapon: vec![],
         tools: vec![],
+        tool_choice: vec![],
+        lang: vec![],
         saving_throws: vec![],
         skill: vec![],
-        prof_point: 0
+        prof_point: 0,
+        hit_die: 0,
+        asi_level: vec![],
+        spellcasting_level: None,
+        multiclass_req: vec![],
+        multiclass_armor: vec![],
+        multiclass_weapon: vec![],
+        multiclass_tools: vec![],
+        multiclass_tool_choice: vec![],
+        multiclass_prof_point: 0
     }
 );
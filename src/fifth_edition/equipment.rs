@@ -0,0 +1,1154 @@
+use crate::common::DamageType;
+use crate::common::profeciency::{Armor, Profeciency, Tools, Weapon};
+
+/* ---------
+   | Macro |
+   --------- */
+macro_rules! new_armor {
+    ($($armor:ident {
+        name: $name:expr,
+        category: $category:expr,
+        ac: $ac:expr,
+        dex_bonus: $dex_bonus:expr,
+        strength: $strength:expr,
+        stealth_disadvantage: $stealth:expr,
+        weight: $weight:expr
+    })*) => {
+        #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+        pub enum ArmorItem {
+            $($armor,)*
+        }
+
+        impl ArmorItem {
+            /// Return display name of armor
+            pub fn get_name(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$armor => $name,
+                    )*
+                }
+            }
+
+            /// Return proficiency category of armor
+            pub fn get_category(&self) -> Armor {
+                match self {
+                    $(
+                        Self::$armor => $category,
+                    )*
+                }
+            }
+
+            /// Return base armor class, or bonus for shields
+            pub fn get_ac(&self) -> usize {
+                match self {
+                    $(
+                        Self::$armor => $ac,
+                    )*
+                }
+            }
+
+            /// Return how much of the DEX modifier is
+            /// added to armor class
+            pub fn get_dex_bonus(&self) -> DexBonus {
+                match self {
+                    $(
+                        Self::$armor => $dex_bonus,
+                    )*
+                }
+            }
+
+            /// Return STR score needed to avoid a speed penalty
+            pub fn get_strength(&self) -> usize {
+                match self {
+                    $(
+                        Self::$armor => $strength,
+                    )*
+                }
+            }
+
+            /// Return whether armor gives disadvantage on
+            /// Stealth checks
+            pub fn get_stealth_disadvantage(&self) -> bool {
+                match self {
+                    $(
+                        Self::$armor => $stealth,
+                    )*
+                }
+            }
+
+            /// Return weight of armor in pounds
+            pub fn get_weight(&self) -> f32 {
+                match self {
+                    $(
+                        Self::$armor => $weight,
+                    )*
+                }
+            }
+        }
+    };
+}
+
+macro_rules! new_weapon {
+    ($($weapon:ident {
+        category: $category:expr,
+        range: $range:expr,
+        damage: $damage:expr,
+        damage_type: $damage_type:expr,
+        weight: $weight:expr,
+        cost: $cost:expr,
+        property: $property:expr
+    })*) => {
+        impl Weapon {
+            /// Return 5th edition statistics of weapon
+            ///
+            /// Example:
+            /// ```
+            /// use role4me_lib::ed_5;
+            /// use role4me_lib::prelude::*;
+            ///
+            /// let stat = Weapon::Longsword.get_stat();
+            ///
+            /// assert_eq!(stat.category, ed_5::WeaponCategory::Martial);
+            /// assert_eq!(stat.damage, (1, 8));
+            /// assert!(stat.property.contains(&ed_5::WeaponProperty::Versatile(10)));
+            /// ```
+            pub fn get_stat(&self) -> WeaponStat {
+                use WeaponCategory::*;
+                use WeaponRange::*;
+                use WeaponProperty::*;
+                use DamageType::*;
+                match self {
+                    $(
+                        Self::$weapon => WeaponStat {
+                            category: $category,
+                            range: $range,
+                            damage: $damage,
+                            damage_type: $damage_type,
+                            weight: $weight,
+                            cost: $cost,
+                            property: $property,
+                        },
+                    )*
+                }
+            }
+        }
+    };
+}
+
+macro_rules! new_gear {
+    ($($gear:ident {
+        name: $name:expr,
+        weight: $weight:expr,
+        capacity: $capacity:expr
+    })*) => {
+        #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+        pub enum Gear {
+            $($gear,)*
+        }
+
+        impl Gear {
+            /// Return display name of gear
+            pub fn get_name(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$gear => $name,
+                    )*
+                }
+            }
+
+            /// Return weight of gear in pounds
+            pub fn get_weight(&self) -> f32 {
+                match self {
+                    $(
+                        Self::$gear => $weight,
+                    )*
+                }
+            }
+
+            /// Return pounds of gear a container holds,
+            /// None when gear is not a container
+            pub fn get_capacity(&self) -> Option<f32> {
+                match self {
+                    $(
+                        Self::$gear => $capacity,
+                    )*
+                }
+            }
+        }
+    };
+}
+
+/* ----------
+   | Struct |
+   ---------- */
+/// Statistics of a weapon
+#[derive(PartialEq, Debug, Clone)]
+pub struct WeaponStat {
+    pub category: WeaponCategory,
+    pub range: WeaponRange,
+    /// Number and sides of damage dice
+    pub damage: (usize, usize),
+    /// None when weapon deals no damage
+    pub damage_type: Option<DamageType>,
+    /// Weight in pounds
+    pub weight: f32,
+    /// Cost in copper pieces
+    pub cost: usize,
+    pub property: Vec<WeaponProperty>
+}
+
+/* --------
+   | Enum |
+   -------- */
+/// Training needed to use a weapon
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum WeaponCategory {
+    Simple, Martial
+}
+
+/// Weapon proficiency granted by a race, class or feat
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum WeaponProf {
+    /// Every weapon of a category
+    Category(WeaponCategory),
+    /// A single weapon
+    Single(Weapon)
+}
+
+/// Whether a weapon is used in melee or at range
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum WeaponRange {
+    Melee, Ranged
+}
+
+/// Property of a weapon
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum WeaponProperty {
+    /// Attack with STR or DEX
+    Finesse,
+    /// Suited for two-weapon fighting
+    Light,
+    /// Disadvantage for Small creatures
+    Heavy,
+    /// Adds 5 feet of reach
+    Reach,
+    /// Needs two hands to attack
+    TwoHanded,
+    /// Can be thrown, range in feet
+    Thrown { normal: usize, long: usize },
+    /// Damage die sides when used with two hands
+    Versatile(usize),
+    /// One attack per action
+    Loading,
+    /// Needs ammunition, range in feet
+    Ammunition { normal: usize, long: usize },
+    /// Special rules apply
+    Special
+}
+
+/// DEX modifier added to armor class by armor
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum DexBonus {
+    /// Whole modifier is added
+    Full,
+    /// Modifier is added up to a maximum
+    Max(isize),
+    /// Modifier is not added
+    Ignored
+}
+
+/// Anything that can be carried in an inventory
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum Item {
+    Weapon(Weapon),
+    Armor(ArmorItem),
+    Gear(Gear),
+    Tool(Tools)
+}
+
+/// Category an item belongs to
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum ItemCategory {
+    Weapon, Armor, Gear, Tool, Container
+}
+
+/// Rule used to check encumbrance
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum EncumbranceRule {
+    /// Only carrying capacity is checked
+    Standard,
+    /// Speed drops past a third and two thirds
+    /// of carrying capacity
+    Variant
+}
+
+/// Drawback of equipped armor
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum ArmorPenalty {
+    /// Armor or shield worn without proficiency,
+    /// disadvantage on STR and DEX based rolls and
+    /// attack rolls, and no spellcasting
+    NotProficient,
+    /// STR score below armor requirement, speed
+    /// drops by 10 feet
+    Strength,
+    /// Disadvantage on Stealth checks
+    StealthDisadvantage
+}
+
+/// Encumbrance level of carried weight
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Encumbrance {
+    Unencumbered,
+    /// Speed drops by 10 feet (variant rule)
+    Encumbered,
+    /// Speed drops by 20 feet, and disadvantage on STR,
+    /// DEX and CON based rolls (variant rule)
+    HeavilyEncumbered,
+    /// Carrying capacity exceeded, speed drops to 5 feet
+    OverCapacity,
+    /// Push, drag or lift limit exceeded, unable to move
+    Immobile
+}
+
+impl WeaponCategory {
+    /// Return every weapon of category
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let simple = ed_5::WeaponCategory::Simple.get_weapon();
+    ///
+    /// assert!(simple.contains(&Weapon::Mace));
+    /// assert!(!simple.contains(&Weapon::Longsword));
+    /// ```
+    pub fn get_weapon(&self) -> Vec<Weapon> {
+        Weapon::get_all()
+            .into_iter()
+            .filter(|weapon| weapon.get_stat().category == *self)
+            .collect()
+    }
+}
+
+impl WeaponProf {
+    /// Return every weapon covered by proficiency
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let prof = ed_5::WeaponProf::Single(Weapon::Rapier);
+    ///
+    /// assert_eq!(prof.get_weapon(), vec![Weapon::Rapier]);
+    /// assert_eq!(ed_5::WeaponProf::Category(ed_5::WeaponCategory::Martial)
+    ///     .get_weapon().len(), 23);
+    /// ```
+    pub fn get_weapon(&self) -> Vec<Weapon> {
+        match self {
+            Self::Category(category) => category.get_weapon(),
+            Self::Single(weapon) => vec![weapon.clone()]
+        }
+    }
+}
+
+impl Item {
+    /// Return display name of item
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// assert_eq!(ed_5::Item::Weapon(Weapon::Longsword).get_name(), "Longsword");
+    /// assert_eq!(ed_5::Item::Gear(ed_5::Gear::RopeHempen).get_name(), "Hempen Rope (50 feet)");
+    /// ```
+    pub fn get_name(&self) -> String {
+        match self {
+            Self::Weapon(weapon) => weapon.as_string(),
+            Self::Armor(armor) => armor.get_name().to_string(),
+            Self::Gear(gear) => gear.get_name().to_string(),
+            Self::Tool(tool) => tool.get_name().to_string()
+        }
+    }
+
+    /// Return weight of a single item in pounds
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// assert_eq!(ed_5::Item::Weapon(Weapon::Dart).get_weight(), 0.25);
+    /// assert_eq!(ed_5::Item::Armor(ed_5::ArmorItem::Plate).get_weight(), 65.0);
+    /// ```
+    pub fn get_weight(&self) -> f32 {
+        match self {
+            Self::Weapon(weapon) => weapon.get_stat().weight,
+            Self::Armor(armor) => armor.get_weight(),
+            Self::Gear(gear) => gear.get_weight(),
+            Self::Tool(tool) => tool.get_weight()
+        }
+    }
+
+    /// Return category of item
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// assert_eq!(
+    ///     ed_5::Item::Gear(ed_5::Gear::Backpack).get_category(),
+    ///     ed_5::ItemCategory::Container
+    /// );
+    /// ```
+    pub fn get_category(&self) -> ItemCategory {
+        match self {
+            Self::Weapon(_) => ItemCategory::Weapon,
+            Self::Armor(_) => ItemCategory::Armor,
+            Self::Gear(gear) if gear.get_capacity().is_some() => ItemCategory::Container,
+            Self::Gear(_) => ItemCategory::Gear,
+            Self::Tool(_) => ItemCategory::Tool
+        }
+    }
+}
+
+/* -----------
+   | Weapons |
+   ----------- */
+new_weapon!(
+    Club {
+        category: Simple,
+        range: Melee,
+        damage: (1, 4),
+        damage_type: Some(Bludgeoning),
+        weight: 2.0,
+        cost: 10,
+        property: vec![Light]
+    }
+    Dagger {
+        category: Simple,
+        range: Melee,
+        damage: (1, 4),
+        damage_type: Some(Piercing),
+        weight: 1.0,
+        cost: 200,
+        property: vec![Finesse, Light, Thrown { normal: 20, long: 60 }]
+    }
+    Greatclub {
+        category: Simple,
+        range: Melee,
+        damage: (1, 8),
+        damage_type: Some(Bludgeoning),
+        weight: 10.0,
+        cost: 20,
+        property: vec![TwoHanded]
+    }
+    Handaxe {
+        category: Simple,
+        range: Melee,
+        damage: (1, 6),
+        damage_type: Some(Slashing),
+        weight: 2.0,
+        cost: 500,
+        property: vec![Light, Thrown { normal: 20, long: 60 }]
+    }
+    Javelin {
+        category: Simple,
+        range: Melee,
+        damage: (1, 6),
+        damage_type: Some(Piercing),
+        weight: 2.0,
+        cost: 50,
+        property: vec![Thrown { normal: 30, long: 120 }]
+    }
+    LightHammer {
+        category: Simple,
+        range: Melee,
+        damage: (1, 4),
+        damage_type: Some(Bludgeoning),
+        weight: 2.0,
+        cost: 200,
+        property: vec![Light, Thrown { normal: 20, long: 60 }]
+    }
+    Mace {
+        category: Simple,
+        range: Melee,
+        damage: (1, 6),
+        damage_type: Some(Bludgeoning),
+        weight: 4.0,
+        cost: 500,
+        property: vec![]
+    }
+    Quaterstaff {
+        category: Simple,
+        range: Melee,
+        damage: (1, 6),
+        damage_type: Some(Bludgeoning),
+        weight: 4.0,
+        cost: 20,
+        property: vec![Versatile(8)]
+    }
+    Sickle {
+        category: Simple,
+        range: Melee,
+        damage: (1, 4),
+        damage_type: Some(Slashing),
+        weight: 2.0,
+        cost: 100,
+        property: vec![Light]
+    }
+    Spear {
+        category: Simple,
+        range: Melee,
+        damage: (1, 6),
+        damage_type: Some(Piercing),
+        weight: 3.0,
+        cost: 100,
+        property: vec![Thrown { normal: 20, long: 60 }, Versatile(8)]
+    }
+    LightCrossbow {
+        category: Simple,
+        range: Ranged,
+        damage: (1, 8),
+        damage_type: Some(Piercing),
+        weight: 5.0,
+        cost: 2500,
+        property: vec![Ammunition { normal: 80, long: 320 }, Loading, TwoHanded]
+    }
+    Dart {
+        category: Simple,
+        range: Ranged,
+        damage: (1, 4),
+        damage_type: Some(Piercing),
+        weight: 0.25,
+        cost: 5,
+        property: vec![Finesse, Thrown { normal: 20, long: 60 }]
+    }
+    Shortbow {
+        category: Simple,
+        range: Ranged,
+        damage: (1, 6),
+        damage_type: Some(Piercing),
+        weight: 2.0,
+        cost: 2500,
+        property: vec![Ammunition { normal: 80, long: 320 }, TwoHanded]
+    }
+    Sling {
+        category: Simple,
+        range: Ranged,
+        damage: (1, 4),
+        damage_type: Some(Bludgeoning),
+        weight: 0.0,
+        cost: 10,
+        property: vec![Ammunition { normal: 30, long: 120 }]
+    }
+    Battleaxe {
+        category: Martial,
+        range: Melee,
+        damage: (1, 8),
+        damage_type: Some(Slashing),
+        weight: 4.0,
+        cost: 1000,
+        property: vec![Versatile(10)]
+    }
+    Flail {
+        category: Martial,
+        range: Melee,
+        damage: (1, 8),
+        damage_type: Some(Bludgeoning),
+        weight: 2.0,
+        cost: 1000,
+        property: vec![]
+    }
+    Glaive {
+        category: Martial,
+        range: Melee,
+        damage: (1, 10),
+        damage_type: Some(Slashing),
+        weight: 6.0,
+        cost: 2000,
+        property: vec![Heavy, Reach, TwoHanded]
+    }
+    Greataxe {
+        category: Martial,
+        range: Melee,
+        damage: (1, 12),
+        damage_type: Some(Slashing),
+        weight: 7.0,
+        cost: 3000,
+        property: vec![Heavy, TwoHanded]
+    }
+    Greatsword {
+        category: Martial,
+        range: Melee,
+        damage: (2, 6),
+        damage_type: Some(Slashing),
+        weight: 6.0,
+        cost: 5000,
+        property: vec![Heavy, TwoHanded]
+    }
+    Halberd {
+        category: Martial,
+        range: Melee,
+        damage: (1, 10),
+        damage_type: Some(Slashing),
+        weight: 6.0,
+        cost: 2000,
+        property: vec![Heavy, Reach, TwoHanded]
+    }
+    Lance {
+        category: Martial,
+        range: Melee,
+        damage: (1, 12),
+        damage_type: Some(Piercing),
+        weight: 6.0,
+        cost: 1000,
+        property: vec![Reach, Special]
+    }
+    Longsword {
+        category: Martial,
+        range: Melee,
+        damage: (1, 8),
+        damage_type: Some(Slashing),
+        weight: 3.0,
+        cost: 1500,
+        property: vec![Versatile(10)]
+    }
+    Maul {
+        category: Martial,
+        range: Melee,
+        damage: (2, 6),
+        damage_type: Some(Bludgeoning),
+        weight: 10.0,
+        cost: 1000,
+        property: vec![Heavy, TwoHanded]
+    }
+    Morningstar {
+        category: Martial,
+        range: Melee,
+        damage: (1, 8),
+        damage_type: Some(Piercing),
+        weight: 4.0,
+        cost: 1500,
+        property: vec![]
+    }
+    Pike {
+        category: Martial,
+        range: Melee,
+        damage: (1, 10),
+        damage_type: Some(Piercing),
+        weight: 18.0,
+        cost: 500,
+        property: vec![Heavy, Reach, TwoHanded]
+    }
+    Rapier {
+        category: Martial,
+        range: Melee,
+        damage: (1, 8),
+        damage_type: Some(Piercing),
+        weight: 2.0,
+        cost: 2500,
+        property: vec![Finesse]
+    }
+    Scimitar {
+        category: Martial,
+        range: Melee,
+        damage: (1, 6),
+        damage_type: Some(Slashing),
+        weight: 3.0,
+        cost: 2500,
+        property: vec![Finesse, Light]
+    }
+    Shortsword {
+        category: Martial,
+        range: Melee,
+        damage: (1, 6),
+        damage_type: Some(Piercing),
+        weight: 2.0,
+        cost: 1000,
+        property: vec![Finesse, Light]
+    }
+    Trident {
+        category: Martial,
+        range: Melee,
+        damage: (1, 6),
+        damage_type: Some(Piercing),
+        weight: 4.0,
+        cost: 500,
+        property: vec![Thrown { normal: 20, long: 60 }, Versatile(8)]
+    }
+    WarPick {
+        category: Martial,
+        range: Melee,
+        damage: (1, 8),
+        damage_type: Some(Piercing),
+        weight: 2.0,
+        cost: 500,
+        property: vec![]
+    }
+    Warhammer {
+        category: Martial,
+        range: Melee,
+        damage: (1, 8),
+        damage_type: Some(Bludgeoning),
+        weight: 2.0,
+        cost: 1500,
+        property: vec![Versatile(10)]
+    }
+    Whip {
+        category: Martial,
+        range: Melee,
+        damage: (1, 4),
+        damage_type: Some(Slashing),
+        weight: 3.0,
+        cost: 200,
+        property: vec![Finesse, Reach]
+    }
+    Blowgun {
+        category: Martial,
+        range: Ranged,
+        damage: (1, 1),
+        damage_type: Some(Piercing),
+        weight: 1.0,
+        cost: 1000,
+        property: vec![Ammunition { normal: 25, long: 100 }, Loading]
+    }
+    HandCrossbow {
+        category: Martial,
+        range: Ranged,
+        damage: (1, 6),
+        damage_type: Some(Piercing),
+        weight: 3.0,
+        cost: 7500,
+        property: vec![Ammunition { normal: 30, long: 120 }, Light, Loading]
+    }
+    HeavyCrossbow {
+        category: Martial,
+        range: Ranged,
+        damage: (1, 10),
+        damage_type: Some(Piercing),
+        weight: 18.0,
+        cost: 5000,
+        property: vec![Ammunition { normal: 100, long: 400 }, Heavy, Loading, TwoHanded]
+    }
+    Longbow {
+        category: Martial,
+        range: Ranged,
+        damage: (1, 8),
+        damage_type: Some(Piercing),
+        weight: 2.0,
+        cost: 5000,
+        property: vec![Ammunition { normal: 150, long: 600 }, Heavy, TwoHanded]
+    }
+    Net {
+        category: Martial,
+        range: Ranged,
+        damage: (0, 0),
+        damage_type: None,
+        weight: 3.0,
+        cost: 100,
+        property: vec![Special, Thrown { normal: 5, long: 15 }]
+    }
+);
+
+/* ---------
+   | Armor |
+   --------- */
+new_armor!(
+    Padded {
+        name: "Padded",
+        category: Armor::Light,
+        ac: 11,
+        dex_bonus: DexBonus::Full,
+        strength: 0,
+        stealth_disadvantage: true,
+        weight: 8.0
+    }
+    Leather {
+        name: "Leather",
+        category: Armor::Light,
+        ac: 11,
+        dex_bonus: DexBonus::Full,
+        strength: 0,
+        stealth_disadvantage: false,
+        weight: 10.0
+    }
+    StuddedLeather {
+        name: "Studded Leather",
+        category: Armor::Light,
+        ac: 12,
+        dex_bonus: DexBonus::Full,
+        strength: 0,
+        stealth_disadvantage: false,
+        weight: 13.0
+    }
+    Hide {
+        name: "Hide",
+        category: Armor::Medium,
+        ac: 12,
+        dex_bonus: DexBonus::Max(2),
+        strength: 0,
+        stealth_disadvantage: false,
+        weight: 12.0
+    }
+    ChainShirt {
+        name: "Chain Shirt",
+        category: Armor::Medium,
+        ac: 13,
+        dex_bonus: DexBonus::Max(2),
+        strength: 0,
+        stealth_disadvantage: false,
+        weight: 20.0
+    }
+    ScaleMail {
+        name: "Scale Mail",
+        category: Armor::Medium,
+        ac: 14,
+        dex_bonus: DexBonus::Max(2),
+        strength: 0,
+        stealth_disadvantage: true,
+        weight: 45.0
+    }
+    Breastplate {
+        name: "Breastplate",
+        category: Armor::Medium,
+        ac: 14,
+        dex_bonus: DexBonus::Max(2),
+        strength: 0,
+        stealth_disadvantage: false,
+        weight: 20.0
+    }
+    HalfPlate {
+        name: "Half Plate",
+        category: Armor::Medium,
+        ac: 15,
+        dex_bonus: DexBonus::Max(2),
+        strength: 0,
+        stealth_disadvantage: true,
+        weight: 40.0
+    }
+    RingMail {
+        name: "Ring Mail",
+        category: Armor::Heavy,
+        ac: 14,
+        dex_bonus: DexBonus::Ignored,
+        strength: 0,
+        stealth_disadvantage: true,
+        weight: 40.0
+    }
+    ChainMail {
+        name: "Chain Mail",
+        category: Armor::Heavy,
+        ac: 16,
+        dex_bonus: DexBonus::Ignored,
+        strength: 13,
+        stealth_disadvantage: true,
+        weight: 55.0
+    }
+    Splint {
+        name: "Splint",
+        category: Armor::Heavy,
+        ac: 17,
+        dex_bonus: DexBonus::Ignored,
+        strength: 15,
+        stealth_disadvantage: true,
+        weight: 60.0
+    }
+    Plate {
+        name: "Plate",
+        category: Armor::Heavy,
+        ac: 18,
+        dex_bonus: DexBonus::Ignored,
+        strength: 15,
+        stealth_disadvantage: true,
+        weight: 65.0
+    }
+    Shield {
+        name: "Shield",
+        category: Armor::Shield,
+        ac: 2,
+        dex_bonus: DexBonus::Full,
+        strength: 0,
+        stealth_disadvantage: false,
+        weight: 6.0
+    }
+);
+
+/* --------
+   | Gear |
+   -------- */
+new_gear!(
+    Arrows {
+        name: "Arrows (20)",
+        weight: 1.0,
+        capacity: None
+    }
+    Backpack {
+        name: "Backpack",
+        weight: 5.0,
+        capacity: Some(30.0)
+    }
+    BallBearings {
+        name: "Ball Bearings (bag of 1,000)",
+        weight: 2.0,
+        capacity: None
+    }
+    Bedroll {
+        name: "Bedroll",
+        weight: 7.0,
+        capacity: None
+    }
+    Blanket {
+        name: "Blanket",
+        weight: 3.0,
+        capacity: None
+    }
+    Book {
+        name: "Book",
+        weight: 5.0,
+        capacity: None
+    }
+    Caltrops {
+        name: "Caltrops (bag of 20)",
+        weight: 2.0,
+        capacity: None
+    }
+    Candle {
+        name: "Candle",
+        weight: 0.0,
+        capacity: None
+    }
+    CaseScroll {
+        name: "Scroll Case",
+        weight: 1.0,
+        capacity: None
+    }
+    Chest {
+        name: "Chest",
+        weight: 25.0,
+        capacity: Some(300.0)
+    }
+    ClimbersKit {
+        name: "Climber's Kit",
+        weight: 12.0,
+        capacity: None
+    }
+    ClothesCommon {
+        name: "Common Clothes",
+        weight: 3.0,
+        capacity: None
+    }
+    ClothesCostume {
+        name: "Costume Clothes",
+        weight: 4.0,
+        capacity: None
+    }
+    ClothesFine {
+        name: "Fine Clothes",
+        weight: 6.0,
+        capacity: None
+    }
+    ClothesTravelers {
+        name: "Traveler's Clothes",
+        weight: 4.0,
+        capacity: None
+    }
+    ClothesVestments {
+        name: "Vestments",
+        weight: 4.0,
+        capacity: None
+    }
+    ComponentPouch {
+        name: "Component Pouch",
+        weight: 2.0,
+        capacity: None
+    }
+    ConTools {
+        name: "Tools of the Con",
+        weight: 3.0,
+        capacity: None
+    }
+    CrossbowBolts {
+        name: "Crossbow Bolts (20)",
+        weight: 1.5,
+        capacity: None
+    }
+    Crowbar {
+        name: "Crowbar",
+        weight: 5.0,
+        capacity: None
+    }
+    FavorOfAdmirer {
+        name: "Favor of an Admirer",
+        weight: 0.0,
+        capacity: None
+    }
+    GoldPiece {
+        name: "Gold Piece",
+        weight: 0.02,
+        capacity: None
+    }
+    GrapplingHook {
+        name: "Grappling Hook",
+        weight: 4.0,
+        capacity: None
+    }
+    HealersKit {
+        name: "Healer's Kit",
+        weight: 3.0,
+        capacity: None
+    }
+    HolySymbol {
+        name: "Holy Symbol",
+        weight: 1.0,
+        capacity: None
+    }
+    HuntingTrap {
+        name: "Hunting Trap",
+        weight: 25.0,
+        capacity: None
+    }
+    Incense {
+        name: "Incense (stick)",
+        weight: 0.0,
+        capacity: None
+    }
+    Ink {
+        name: "Ink (1 ounce bottle)",
+        weight: 0.0,
+        capacity: None
+    }
+    Insignia {
+        name: "Insignia of Rank",
+        weight: 0.0,
+        capacity: None
+    }
+    IronPot {
+        name: "Iron Pot",
+        weight: 10.0,
+        capacity: None
+    }
+    Lantern {
+        name: "Hooded Lantern",
+        weight: 2.0,
+        capacity: None
+    }
+    Letter {
+        name: "Letter",
+        weight: 0.0,
+        capacity: None
+    }
+    LuckyCharm {
+        name: "Lucky Charm",
+        weight: 0.0,
+        capacity: None
+    }
+    Map {
+        name: "Map",
+        weight: 0.0,
+        capacity: None
+    }
+    MessKit {
+        name: "Mess Kit",
+        weight: 1.0,
+        capacity: None
+    }
+    Oil {
+        name: "Oil (flask)",
+        weight: 1.0,
+        capacity: None
+    }
+    PetMouse {
+        name: "Pet Mouse",
+        weight: 0.0,
+        capacity: None
+    }
+    PotionOfHealing {
+        name: "Potion of Healing",
+        weight: 0.5,
+        capacity: None
+    }
+    Pouch {
+        name: "Pouch",
+        weight: 1.0,
+        capacity: Some(6.0)
+    }
+    Quill {
+        name: "Quill",
+        weight: 0.0,
+        capacity: None
+    }
+    Quiver {
+        name: "Quiver",
+        weight: 1.0,
+        capacity: None
+    }
+    Rations {
+        name: "Rations (1 day)",
+        weight: 2.0,
+        capacity: None
+    }
+    RopeHempen {
+        name: "Hempen Rope (50 feet)",
+        weight: 10.0,
+        capacity: None
+    }
+    RopeSilk {
+        name: "Silk Rope (50 feet)",
+        weight: 5.0,
+        capacity: None
+    }
+    Sack {
+        name: "Sack",
+        weight: 0.5,
+        capacity: Some(30.0)
+    }
+    ScrollOfPedigree {
+        name: "Scroll of Pedigree",
+        weight: 0.0,
+        capacity: None
+    }
+    Shovel {
+        name: "Shovel",
+        weight: 5.0,
+        capacity: None
+    }
+    SignetRing {
+        name: "Signet Ring",
+        weight: 0.0,
+        capacity: None
+    }
+    SmallKnife {
+        name: "Small Knife",
+        weight: 0.5,
+        capacity: None
+    }
+    Tinderbox {
+        name: "Tinderbox",
+        weight: 1.0,
+        capacity: None
+    }
+    Token {
+        name: "Token",
+        weight: 0.0,
+        capacity: None
+    }
+    Torch {
+        name: "Torch",
+        weight: 1.0,
+        capacity: None
+    }
+    Trophy {
+        name: "Trophy",
+        weight: 0.0,
+        capacity: None
+    }
+    Waterskin {
+        name: "Waterskin",
+        weight: 5.0,
+        capacity: None
+    }
+);
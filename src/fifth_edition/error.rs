@@ -0,0 +1,230 @@
+use std::{error::Error, fmt::Display};
+use crate::common::AP;
+use crate::common::profeciency::{Language, Skill, Tools, Weapon};
+use super::AbilityImprovement;
+use super::class::Class;
+use super::equipment::Item;
+use super::feat::{Feat, FeatPrerequisite};
+use super::traits::RacialTrait;
+
+/* --------
+   | Enum |
+   -------- */
+/// Errors returned by fallible `Character` methods
+///
+/// Every variant carries the offending value(s) so that
+/// callers can build their own (localised) messages
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CharacterError {
+    /// A point buy score is outside of 8..=15
+    PointOutOfRange { points: [usize; 6], value: usize },
+    /// Point buy cost exceeded the budget
+    PointBuyExceeded { limit: usize, assigned: usize },
+    /// A sequence index is not within 0..=5
+    SequenceOutOfRange { sequence: [usize; 6], index: usize },
+    /// A sequence index appears more than once
+    DuplicateSequence { sequence: [usize; 6], index: usize },
+    /// No valid sequence has been assigned yet
+    NoSequence,
+    /// Race has no ability point left to assign
+    NoRaceAbilityPoint,
+    /// Ability already received a point from race
+    AbilityAlreadyAssigned(AP),
+    /// Ability did not receive a point from race
+    AbilityNotAssigned(AP),
+    /// No ability bonus left from race can be put on the ability
+    AbilityNotAllowed(AP),
+    /// Race or background has no language point left to assign
+    NoLanguagePoint,
+    /// Language is already known
+    LanguageKnown(Language),
+    /// Language was not manually assigned
+    LanguageNotAssigned(Language),
+    /// Language category cannot be chosen with a language point
+    LanguageRestricted(Language),
+    /// Skill is not proficient
+    SkillNotProficient(Skill),
+    /// Skill already has expertise
+    ExpertiseKnown(Skill),
+    /// Class has no skill left to choose
+    NoClassSkillPoint,
+    /// Skill is already proficient
+    SkillKnown(Skill),
+    /// Skill is not on the class skill list
+    SkillNotAvailable(Skill),
+    /// Character is already at level 20
+    MaxLevel,
+    /// Ability score too low to multiclass
+    MulticlassRequirement { class: Class, ability: AP, required: usize },
+    /// Unknown class cannot be multiclassed
+    MulticlassUnknown,
+    /// Class has not been taken
+    ClassNotTaken(Class),
+    /// Race, class or background has no tool point left to assign
+    NoToolPoint,
+    /// Tool is already proficient
+    ToolKnown(Tools),
+    /// Tool was not manually assigned
+    ToolNotAssigned(Tools),
+    /// Tool is not one of the tools to choose from
+    ToolNotAvailable(Tools),
+    /// Race has no weapon point left to assign
+    NoWeaponPoint,
+    /// Weapon is already proficient
+    WeaponKnown(Weapon),
+    /// Weapon is not one of the weapons to choose from
+    WeaponNotAvailable(Weapon),
+    /// Weapon was not manually assigned
+    WeaponNotAssigned(Weapon),
+    /// Race has no trait point left to assign
+    NoTraitPoint,
+    /// Racial trait is already known
+    TraitKnown(RacialTrait),
+    /// Racial trait is not one of the traits to choose from
+    TraitNotAvailable(RacialTrait),
+    /// Racial trait was not manually assigned
+    TraitNotAssigned(RacialTrait),
+    /// Custom background needs exactly two skills
+    CustomBackgroundSkill(usize),
+    /// Custom background needs exactly two tools or languages
+    CustomBackgroundProficiency(usize),
+    /// Custom background needs a feature from another background
+    CustomBackgroundFeature,
+    /// Race has no skill point left to assign
+    NoRaceSkillPoint,
+    /// Skill was not assigned from race
+    SkillNotAssigned(Skill),
+    /// No feat slot left from race or ability score improvements
+    NoFeatPoint,
+    /// Feat has already been taken
+    FeatKnown(Feat),
+    /// Feat has not been taken
+    FeatNotTaken(Feat),
+    /// Chosen options of feat are not allowed
+    FeatInvalid(Feat),
+    /// Feat prerequisite is not met
+    FeatRequirement { feat: Feat, prerequisite: FeatPrerequisite },
+    /// No ability score improvement left to spend
+    NoAsiPoint,
+    /// Both increases of a split improvement target one ability
+    AsiSameAbility(AP),
+    /// Ability score would be raised past 20
+    AbilityScoreMax(AP),
+    /// Ability score improvement has not been spent
+    AsiNotTaken(AbilityImprovement),
+    /// Item is not in inventory
+    ItemNotCarried(Item),
+    /// Fewer items carried than requested
+    NotEnoughItem { item: Item, carried: usize },
+}
+
+impl Display for CharacterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::PointOutOfRange { points, value } => write!(f,
+                "Point must be within 8 & 15, got {} in {:?}",
+                value, points),
+            Self::PointBuyExceeded { limit, assigned } => write!(f,
+                "Points sum exceeded limit, Limit: {} Assigned: {}",
+                limit, assigned),
+            Self::SequenceOutOfRange { sequence, index } => write!(f,
+                "Sequence value must be between 0 and 5, got {} in {:?}",
+                index, sequence),
+            Self::DuplicateSequence { sequence, index } => write!(f,
+                "Sequence value {} is repeated in {:?}",
+                index, sequence),
+            Self::NoSequence => write!(f, "No sequence assigned"),
+            Self::NoRaceAbilityPoint => write!(f,
+                "No ability point left to assign from race"),
+            Self::AbilityAlreadyAssigned(ap) => write!(f,
+                "{:?} already received a point from race", ap),
+            Self::AbilityNotAssigned(ap) => write!(f,
+                "{:?} did not receive a point from race", ap),
+            Self::AbilityNotAllowed(ap) => write!(f,
+                "No ability bonus left from race can be put on {:?}", ap),
+            Self::NoLanguagePoint => write!(f,
+                "No language point left to assign"),
+            Self::LanguageKnown(lang) => write!(f,
+                "{:?} is already known", lang),
+            Self::LanguageNotAssigned(lang) => write!(f,
+                "{:?} was not manually assigned", lang),
+            Self::LanguageRestricted(lang) => write!(f,
+                "{:?} is {:?} and cannot be chosen", lang, lang.get_category()),
+            Self::SkillNotProficient(skill) => write!(f,
+                "{:?} is not proficient", skill),
+            Self::ExpertiseKnown(skill) => write!(f,
+                "{:?} already has expertise", skill),
+            Self::NoClassSkillPoint => write!(f,
+                "No skill left to choose from class"),
+            Self::SkillKnown(skill) => write!(f,
+                "{:?} is already proficient", skill),
+            Self::SkillNotAvailable(skill) => write!(f,
+                "{:?} is not on the class skill list", skill),
+            Self::MaxLevel => write!(f, "Character is already at level 20"),
+            Self::MulticlassRequirement { class, ability, required } => write!(f,
+                "Multiclassing with {:?} requires {:?} {}",
+                class, ability, required),
+            Self::MulticlassUnknown => write!(f,
+                "Unknown class cannot be multiclassed"),
+            Self::ClassNotTaken(class) => write!(f,
+                "{:?} has not been taken", class),
+            Self::NoToolPoint => write!(f,
+                "No tool point left to assign"),
+            Self::ToolKnown(tool) => write!(f,
+                "{:?} is already proficient", tool),
+            Self::ToolNotAssigned(tool) => write!(f,
+                "{:?} was not manually assigned", tool),
+            Self::ToolNotAvailable(tool) => write!(f,
+                "{:?} is not one of the tools to choose from", tool),
+            Self::NoWeaponPoint => write!(f,
+                "No weapon point left to assign"),
+            Self::WeaponKnown(weap) => write!(f,
+                "{:?} is already proficient", weap),
+            Self::WeaponNotAvailable(weap) => write!(f,
+                "{:?} is not one of the weapons to choose from", weap),
+            Self::WeaponNotAssigned(weap) => write!(f,
+                "{:?} was not manually assigned", weap),
+            Self::NoTraitPoint => write!(f,
+                "No trait point left to assign"),
+            Self::TraitKnown(traits) => write!(f,
+                "{:?} is already known", traits),
+            Self::TraitNotAvailable(traits) => write!(f,
+                "{:?} is not one of the traits to choose from", traits),
+            Self::TraitNotAssigned(traits) => write!(f,
+                "{:?} was not manually assigned", traits),
+            Self::CustomBackgroundSkill(count) => write!(f,
+                "Custom background needs 2 skills, got {}", count),
+            Self::CustomBackgroundProficiency(count) => write!(f,
+                "Custom background needs 2 tools or languages, got {}", count),
+            Self::CustomBackgroundFeature => write!(f,
+                "Custom background needs a feature from another background"),
+            Self::NoRaceSkillPoint => write!(f,
+                "No skill point left to assign from race"),
+            Self::SkillNotAssigned(skill) => write!(f,
+                "{:?} was not assigned from race", skill),
+            Self::NoFeatPoint => write!(f, "No feat slot left"),
+            Self::FeatKnown(feat) => write!(f,
+                "{} has already been taken", feat.get_name()),
+            Self::FeatNotTaken(feat) => write!(f,
+                "{:?} has not been taken", feat),
+            Self::FeatInvalid(feat) => write!(f,
+                "{:?} is not a valid choice", feat),
+            Self::FeatRequirement { feat, prerequisite } => write!(f,
+                "{} requires {:?}", feat.get_name(), prerequisite),
+            Self::NoAsiPoint => write!(f,
+                "No ability score improvement left to spend"),
+            Self::AsiSameAbility(ap) => write!(f,
+                "{:?} can't receive both increases of a split improvement", ap),
+            Self::AbilityScoreMax(ap) => write!(f,
+                "{:?} can't be raised past 20", ap),
+            Self::AsiNotTaken(improvement) => write!(f,
+                "{:?} has not been spent", improvement),
+            Self::ItemNotCarried(item) => write!(f,
+                "{} is not carried", item.get_name()),
+            Self::NotEnoughItem { item, carried } => write!(f,
+                "Only {} {} carried", carried, item.get_name()),
+        }
+    }
+}
+
+impl Error for CharacterError {}
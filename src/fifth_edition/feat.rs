@@ -0,0 +1,290 @@
+use crate::common::AP;
+use crate::common::profeciency::{Armor, Skill, Weapon};
+use super::class::Class;
+use super::equipment::WeaponProf::{self, Single};
+
+/* ---------
+   | Macro |
+   --------- */
+macro_rules! new_feat {
+    ($($feat:ident $(($($param:ident: $type:ty),*))? {
+        name: $name:expr,
+        description: $description:expr,
+        prerequisite: $prerequisite:expr,
+        valid: $valid:expr,
+        effect: $effect:expr
+    })*) => {
+        #[derive(PartialEq, Eq, Hash, Debug, Clone)]
+        pub enum Feat {
+            $($feat $(($($type),*))?,)*
+        }
+
+        #[allow(unused_variables)]
+        impl Feat {
+            /// Return display name of feat
+            pub fn get_name(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$feat $(($($param),*))? => $name,
+                    )*
+                }
+            }
+
+            /// Return rule text summary of feat
+            pub fn get_description(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$feat $(($($param),*))? => $description,
+                    )*
+                }
+            }
+
+            /// Return prerequisites to take feat,
+            /// every one of them must be met
+            pub fn get_prerequisite(&self) -> Vec<FeatPrerequisite> {
+                use FeatPrerequisite::*;
+                match self {
+                    $(
+                        Self::$feat $(($($param),*))? => $prerequisite,
+                    )*
+                }
+            }
+
+            /// Return whether chosen options of feat are allowed
+            pub fn is_valid(&self) -> bool {
+                match self {
+                    $(
+                        Self::$feat $(($($param),*))? => $valid,
+                    )*
+                }
+            }
+
+            /// Return mechanical effects of feat
+            pub fn get_effect(&self) -> Vec<FeatEffect> {
+                use FeatEffect::*;
+                match self {
+                    $(
+                        Self::$feat $(($($param),*))? => $effect,
+                    )*
+                }
+            }
+        }
+    };
+}
+
+/* --------
+   | Enum |
+   -------- */
+/// Requirement to take a feat
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum FeatPrerequisite {
+    /// Any one of the ability scores must be met
+    Ability(Vec<(AP, usize)>),
+    /// Proficiency with an armor type
+    ArmorProf(Armor),
+    /// Ability to cast at least one spell
+    Spellcasting,
+    /// Minimum character level
+    Level(usize),
+}
+
+/// Mechanical effect of a feat
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum FeatEffect {
+    /// Increase an ability score by 1, to a maximum of 20
+    AbilityIncrease(AP),
+    /// Raise or lower an ability score, bypassing the
+    /// maximum of 20
+    AbilityAdjust(AP, isize),
+    /// Proficiency in saving throws of an ability
+    SavingThrow(AP),
+    /// Bonus to initiative
+    Initiative(isize),
+    /// Extra hit points gained every level
+    HitPointPerLevel(usize),
+    /// Bonus to passive Perception and Investigation
+    PassiveBonus(isize),
+    /// Walking speed increase in feet
+    Speed(usize),
+    /// Proficiency with an armor type
+    ArmorProf(Armor),
+    /// Proficiency in a skill
+    SkillProf(Skill),
+    /// Proficiency with a weapon or weapon category
+    WeaponProf(WeaponProf),
+    /// Spells learned from a class spell list
+    Spellcasting(Class),
+}
+
+/* ---------
+   | Feats |
+   --------- */
+new_feat!(
+    Actor {
+        name: "Actor",
+        description: "Increase Charisma by 1, advantage on Deception and Performance checks when passing yourself off as a different person, and mimic speech of others.",
+        prerequisite: vec![],
+        valid: true,
+        effect: vec![AbilityIncrease(AP::CHA)]
+    }
+    Alert {
+        name: "Alert",
+        description: "+5 bonus to initiative, can't be surprised while conscious, and hidden creatures gain no advantage on attack rolls against you.",
+        prerequisite: vec![],
+        valid: true,
+        effect: vec![Initiative(5)]
+    }
+    Athlete(ability: AP) {
+        name: "Athlete",
+        description: "Increase Strength or Dexterity by 1, stand up and climb with less movement, and jump after moving only 5 feet.",
+        prerequisite: vec![],
+        valid: matches!(ability, AP::STR | AP::DEX),
+        effect: vec![AbilityIncrease(*ability)]
+    }
+    DefensiveDuelist {
+        name: "Defensive Duelist",
+        description: "Use your reaction to add proficiency bonus to AC against a melee attack while wielding a finesse weapon.",
+        prerequisite: vec![Ability(vec![(AP::DEX, 13)])],
+        valid: true,
+        effect: vec![]
+    }
+    Durable {
+        name: "Durable",
+        description: "Increase Constitution by 1, and regain at least twice your Constitution modifier when rolling a hit die.",
+        prerequisite: vec![],
+        valid: true,
+        effect: vec![AbilityIncrease(AP::CON)]
+    }
+    Grappler {
+        name: "Grappler",
+        description: "Advantage on attack rolls against a creature you are grappling, and attempt to pin it.",
+        prerequisite: vec![Ability(vec![(AP::STR, 13)])],
+        valid: true,
+        effect: vec![]
+    }
+    GreatWeaponMaster {
+        name: "Great Weapon Master",
+        description: "Make a bonus attack after a critical hit or kill, and trade -5 to hit for +10 damage with heavy weapons.",
+        prerequisite: vec![],
+        valid: true,
+        effect: vec![]
+    }
+    HeavilyArmored {
+        name: "Heavily Armored",
+        description: "Increase Strength by 1, and gain proficiency with heavy armor.",
+        prerequisite: vec![ArmorProf(Armor::Medium)],
+        valid: true,
+        effect: vec![AbilityIncrease(AP::STR), ArmorProf(Armor::Heavy)]
+    }
+    InspiringLeader {
+        name: "Inspiring Leader",
+        description: "Spend 10 minutes to grant up to six creatures temporary hit points equal to your level + Charisma modifier.",
+        prerequisite: vec![Ability(vec![(AP::CHA, 13)])],
+        valid: true,
+        effect: vec![]
+    }
+    LightlyArmored(ability: AP) {
+        name: "Lightly Armored",
+        description: "Increase Strength or Dexterity by 1, and gain proficiency with light armor.",
+        prerequisite: vec![],
+        valid: matches!(ability, AP::STR | AP::DEX),
+        effect: vec![AbilityIncrease(*ability), ArmorProf(Armor::Light)]
+    }
+    Lucky {
+        name: "Lucky",
+        description: "Three luck points per long rest to roll an additional d20 for an attack roll, ability check or saving throw.",
+        prerequisite: vec![],
+        valid: true,
+        effect: vec![]
+    }
+    MagicInitiate(class: Class) {
+        name: "Magic Initiate",
+        description: "Learn two cantrips and one 1st level spell from the spell list of a class.",
+        prerequisite: vec![],
+        valid: class.get_spellcasting_level() == Some(1),
+        effect: vec![Spellcasting(*class)]
+    }
+    Mobile {
+        name: "Mobile",
+        description: "Speed increases by 10 feet, dash ignores difficult terrain, and avoid opportunity attacks from creatures you attack.",
+        prerequisite: vec![],
+        valid: true,
+        effect: vec![Speed(10)]
+    }
+    ModeratelyArmored(ability: AP) {
+        name: "Moderately Armored",
+        description: "Increase Strength or Dexterity by 1, and gain proficiency with medium armor and shields.",
+        prerequisite: vec![ArmorProf(Armor::Light)],
+        valid: matches!(ability, AP::STR | AP::DEX),
+        effect: vec![AbilityIncrease(*ability), ArmorProf(Armor::Medium), ArmorProf(Armor::Shield)]
+    }
+    Observant(ability: AP) {
+        name: "Observant",
+        description: "Increase Intelligence or Wisdom by 1, read lips, and +5 bonus to passive Perception and Investigation.",
+        prerequisite: vec![],
+        valid: matches!(ability, AP::INT | AP::WIS),
+        effect: vec![AbilityIncrease(*ability), PassiveBonus(5)]
+    }
+    Resilient(ability: AP) {
+        name: "Resilient",
+        description: "Increase an ability score by 1, and gain proficiency in saving throws using that ability.",
+        prerequisite: vec![],
+        valid: true,
+        effect: vec![AbilityIncrease(*ability), SavingThrow(*ability)]
+    }
+    RitualCaster {
+        name: "Ritual Caster",
+        description: "Cast spells from a ritual book as rituals.",
+        prerequisite: vec![Ability(vec![(AP::INT, 13), (AP::WIS, 13)])],
+        valid: true,
+        effect: vec![]
+    }
+    Sentinel {
+        name: "Sentinel",
+        description: "Opportunity attacks reduce speed to 0, ignore disengage, and react to attacks against allies within 5 feet.",
+        prerequisite: vec![],
+        valid: true,
+        effect: vec![]
+    }
+    Sharpshooter {
+        name: "Sharpshooter",
+        description: "No disadvantage at long range, ignore half and three-quarters cover, and trade -5 to hit for +10 damage with ranged weapons.",
+        prerequisite: vec![],
+        valid: true,
+        effect: vec![]
+    }
+    Skilled(first: Skill, second: Skill, third: Skill) {
+        name: "Skilled",
+        description: "Gain proficiency in three skills of your choice.",
+        prerequisite: vec![],
+        valid: first != second && second != third && first != third,
+        effect: vec![SkillProf(first.clone()), SkillProf(second.clone()), SkillProf(third.clone())]
+    }
+    Tough {
+        name: "Tough",
+        description: "Hit point maximum increases by 2 for every level.",
+        prerequisite: vec![],
+        valid: true,
+        effect: vec![HitPointPerLevel(2)]
+    }
+    WarCaster {
+        name: "War Caster",
+        description: "Advantage on Constitution saves to maintain concentration, perform somatic components with hands full, and cast a spell as an opportunity attack.",
+        prerequisite: vec![Spellcasting],
+        valid: true,
+        effect: vec![]
+    }
+    WeaponMaster(ability: AP, first: Weapon, second: Weapon, third: Weapon, fourth: Weapon) {
+        name: "Weapon Master",
+        description: "Increase Strength or Dexterity by 1, and gain proficiency with four weapons of your choice.",
+        prerequisite: vec![],
+        valid: matches!(ability, AP::STR | AP::DEX)
+            && first != second && first != third && first != fourth
+            && second != third && second != fourth && third != fourth,
+        effect: vec![
+            AbilityIncrease(*ability),
+            WeaponProf(Single(first.clone())), WeaponProf(Single(second.clone())),
+            WeaponProf(Single(third.clone())), WeaponProf(Single(fourth.clone()))
+        ]
+    }
+);
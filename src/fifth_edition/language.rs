@@ -0,0 +1,244 @@
+use crate::common::profeciency::Language;
+
+/* ---------
+   | Macro |
+   --------- */
+macro_rules! new_language {
+    ($($lang:ident {
+        name: $name:expr,
+        category: $category:expr,
+        script: $script:expr,
+        speaker: $speaker:expr
+    })*) => {
+        impl Language {
+            /// Return display name of language
+            ///
+            /// Example:
+            /// ```
+            /// use role4me_lib::prelude::*;
+            ///
+            /// assert_eq!(Language::ThievesCant.get_name(), "Thieves' Cant");
+            /// ```
+            pub fn get_name(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$lang => $name,
+                    )*
+                }
+            }
+
+            /// Return whether language is standard, exotic or secret
+            ///
+            /// Example:
+            /// ```
+            /// use role4me_lib::ed_5;
+            /// use role4me_lib::prelude::*;
+            ///
+            /// assert_eq!(Language::Giant.get_category(), ed_5::LanguageCategory::Standard);
+            /// assert_eq!(Language::Sylvan.get_category(), ed_5::LanguageCategory::Exotic);
+            /// ```
+            pub fn get_category(&self) -> LanguageCategory {
+                use LanguageCategory::*;
+                match self {
+                    $(
+                        Self::$lang => $category,
+                    )*
+                }
+            }
+
+            /// Return script language is written in,
+            /// None when it has no script
+            ///
+            /// Example:
+            /// ```
+            /// use role4me_lib::ed_5;
+            /// use role4me_lib::prelude::*;
+            ///
+            /// assert_eq!(Language::Orc.get_script(), Some(ed_5::Script::Dwarvish));
+            /// assert_eq!(Language::DeepSpeech.get_script(), None);
+            /// ```
+            pub fn get_script(&self) -> Option<Script> {
+                match self {
+                    $(
+                        Self::$lang => $script,
+                    )*
+                }
+            }
+
+            /// Return creatures typically speaking language
+            ///
+            /// Example:
+            /// ```
+            /// use role4me_lib::prelude::*;
+            ///
+            /// assert_eq!(Language::Abyssal.get_speaker(), vec!["Demons"]);
+            /// ```
+            pub fn get_speaker(&self) -> Vec<&'static str> {
+                match self {
+                    $(
+                        Self::$lang => $speaker,
+                    )*
+                }
+            }
+        }
+    };
+}
+
+/* --------
+   | Enum |
+   -------- */
+/// How commonly a language is known
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum LanguageCategory {
+    /// Spoken by common races
+    Standard,
+    /// Spoken by outsiders and rare creatures
+    Exotic,
+    /// Only taught by a class
+    Secret
+}
+
+/// Alphabet a language is written in
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Script {
+    Common, Dwarvish, Elvish,
+    Infernal, Celestial, Draconic
+}
+
+/* -------------
+   | Languages |
+   ------------- */
+new_language!(
+    Common {
+        name: "Common",
+        category: Standard,
+        script: Some(Script::Common),
+        speaker: vec!["Humans"]
+    }
+    Dwarven {
+        name: "Dwarvish",
+        category: Standard,
+        script: Some(Script::Dwarvish),
+        speaker: vec!["Dwarves"]
+    }
+    Elven {
+        name: "Elvish",
+        category: Standard,
+        script: Some(Script::Elvish),
+        speaker: vec!["Elves"]
+    }
+    Giant {
+        name: "Giant",
+        category: Standard,
+        script: Some(Script::Dwarvish),
+        speaker: vec!["Ogres", "Giants"]
+    }
+    Gnomish {
+        name: "Gnomish",
+        category: Standard,
+        script: Some(Script::Dwarvish),
+        speaker: vec!["Gnomes"]
+    }
+    Goblin {
+        name: "Goblin",
+        category: Standard,
+        script: Some(Script::Dwarvish),
+        speaker: vec!["Goblinoids"]
+    }
+    Halfling {
+        name: "Halfling",
+        category: Standard,
+        script: Some(Script::Common),
+        speaker: vec!["Halflings"]
+    }
+    Orc {
+        name: "Orc",
+        category: Standard,
+        script: Some(Script::Dwarvish),
+        speaker: vec!["Orcs"]
+    }
+    Abyssal {
+        name: "Abyssal",
+        category: Exotic,
+        script: Some(Script::Infernal),
+        speaker: vec!["Demons"]
+    }
+    Celestial {
+        name: "Celestial",
+        category: Exotic,
+        script: Some(Script::Celestial),
+        speaker: vec!["Celestials"]
+    }
+    Draconic {
+        name: "Draconic",
+        category: Exotic,
+        script: Some(Script::Draconic),
+        speaker: vec!["Dragons", "Dragonborn"]
+    }
+    DeepSpeech {
+        name: "Deep Speech",
+        category: Exotic,
+        script: None,
+        speaker: vec!["Aboleths", "Cloakers"]
+    }
+    Infernal {
+        name: "Infernal",
+        category: Exotic,
+        script: Some(Script::Infernal),
+        speaker: vec!["Devils"]
+    }
+    Primordial {
+        name: "Primordial",
+        category: Exotic,
+        script: Some(Script::Dwarvish),
+        speaker: vec!["Elementals"]
+    }
+    Aquan {
+        name: "Aquan",
+        category: Exotic,
+        script: Some(Script::Dwarvish),
+        speaker: vec!["Water elementals"]
+    }
+    Auran {
+        name: "Auran",
+        category: Exotic,
+        script: Some(Script::Dwarvish),
+        speaker: vec!["Air elementals"]
+    }
+    Ignan {
+        name: "Ignan",
+        category: Exotic,
+        script: Some(Script::Dwarvish),
+        speaker: vec!["Fire elementals"]
+    }
+    Terran {
+        name: "Terran",
+        category: Exotic,
+        script: Some(Script::Dwarvish),
+        speaker: vec!["Earth elementals"]
+    }
+    Sylvan {
+        name: "Sylvan",
+        category: Exotic,
+        script: Some(Script::Elvish),
+        speaker: vec!["Fey creatures"]
+    }
+    Undercommon {
+        name: "Undercommon",
+        category: Exotic,
+        script: Some(Script::Elvish),
+        speaker: vec!["Underdark traders"]
+    }
+    Druidic {
+        name: "Druidic",
+        category: Secret,
+        script: None,
+        speaker: vec!["Druids"]
+    }
+    ThievesCant {
+        name: "Thieves' Cant",
+        category: Secret,
+        script: None,
+        speaker: vec!["Rogues"]
+    }
+);
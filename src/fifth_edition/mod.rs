@@ -2,32 +2,150 @@
 pub mod race;
 pub mod class;
 pub mod traits;
+pub mod error;
+pub mod background;
+pub mod feat;
+pub mod equipment;
+pub mod language;
+pub mod tool;
 mod tests;
 
-use std::{collections::{BTreeSet, HashSet}, fmt::Debug};
-use crate::common::{AP, Edition, Race, Stat, Size, dice};
-use crate::common::profeciency::{Language, Weapon, Armor, Skill};
+use std::{collections::{BTreeSet, HashSet}, fmt::Debug, hash::Hash};
+use crate::common::{AP, Condition, DamageType, Edition, Race, Size, dice};
+use crate::common::profeciency::{Language, Weapon, Armor, Skill, Tools};
 use race::*;
 use class::Class;
+use error::CharacterError;
+use traits::{RacialTrait, TraitEffect};
+use background::{Background, BackgroundStat, Feature};
+use feat::{Feat, FeatEffect, FeatPrerequisite};
+use language::LanguageCategory;
+use tool::ToolChoice;
+use equipment::{
+    ArmorItem, ArmorPenalty, DexBonus, Encumbrance, EncumbranceRule,
+    Item, WeaponCategory, WeaponProf, WeaponProperty, WeaponRange
+};
+
+/// Method used to gain hit points on level up
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum HitPointMethod {
+    Average, Roll
+}
+
+/// Ability score improvement gained from class levels
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum AbilityImprovement {
+    /// Increase one ability score by 2
+    Single(AP),
+    /// Increase two ability scores by 1
+    Split(AP, AP)
+}
+
+impl AbilityImprovement {
+    /// Return every +1 increase of improvement
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     ed_5::AbilityImprovement::Single(AP::STR).get_ability(),
+    ///     vec![AP::STR, AP::STR]
+    /// );
+    /// ```
+    pub fn get_ability(&self) -> Vec<AP> {
+        match self {
+            Self::Single(ap) => vec![*ap, *ap],
+            Self::Split(first, second) => vec![*first, *second]
+        }
+    }
+}
+
+/// Experience points required to reach each level
+const XP_THRESHOLD: [usize; 20] = [
+    0, 300, 900, 2700, 6500,
+    14000, 23000, 34000, 48000, 64000,
+    85000, 100000, 120000, 140000, 165000,
+    195000, 225000, 265000, 305000, 355000
+];
+
+// Levels taken in a class and choices made from it
+struct ClassLevel {
+    class: Class,
+    level: usize,
+    used_skill: HashSet<Skill>,
+    skill_point: usize,
+    used_tools: HashSet<Tools>,
+    tool_point: usize,
+    hp_roll: Vec<usize>
+}
+
+impl ClassLevel {
+    fn new(class: Class) -> ClassLevel {
+        ClassLevel {
+            class,
+            level: 1,
+            used_skill: HashSet::new(),
+            skill_point: 0,
+            used_tools: HashSet::new(),
+            tool_point: 0,
+            hp_roll: Vec::new()
+        }
+    }
+}
 
 pub struct Character<'a> {
     edition: Edition,
-    class: Class,
-    race: Box<dyn Race + 'a>,
+    class: Vec<ClassLevel>,
+    race: Box<dyn Race<Stat = Stat> + 'a>,
     race_usable_ap: usize,
-    race_used_ability: HashSet<AP>,
+    race_used_ability: Vec<(AP, usize)>,
     race_used_lang: HashSet<Language>,
+    race_used_skill: HashSet<Skill>,
+    race_skill_point: usize,
+    race_used_tools: HashSet<Tools>,
+    race_tool_point: usize,
+    race_used_weap: HashSet<Weapon>,
+    race_weap_point: usize,
+    race_used_trait: HashSet<RacialTrait>,
+    race_trait_point: usize,
+    background: Background,
+    background_used_lang: HashSet<Language>,
+    background_lang_point: usize,
+    background_used_tools: HashSet<Tools>,
+    background_tool_point: usize,
     lang_point: usize,
+    lang_rule: HashSet<LanguageCategory>,
     lang: HashSet<Language>,
+    feat: Vec<Feat>,
+    feat_point: usize,
+    asi: Vec<AbilityImprovement>,
+    asi_point: usize,
+    traits: Vec<RacialTrait>,
     weap: HashSet<Weapon>,
+    weap_category: HashSet<WeaponCategory>,
     armor: HashSet<Armor>,
     skill: HashSet<Skill>,
+    expertise: HashSet<Skill>,
+    tools: HashSet<Tools>,
+    saving_throw: HashSet<AP>,
     speed: usize,
     size: Size,
     ap_unassigned: [usize; 6],
-    ap_seq: Result<BTreeSet<usize>, String>,
+    ap_seq: Result<[usize; 6], CharacterError>,
     base_ap: [usize; 6],
-    buffer_race: Option<Stat>
+    ap_adjustment: [isize; 6],
+    xp: usize,
+    hp_method: HitPointMethod,
+    hp_damage: usize,
+    hp_temp: usize,
+    inventory: Vec<(Item, usize)>,
+    encumbrance_rule: EncumbranceRule,
+    equipped_armor: Option<ArmorItem>,
+    equipped_shield: bool,
+    buffer_race: Option<Stat>,
+    buffer_background: BackgroundStat
 }
 
 impl<'a> Character<'a> {
@@ -45,22 +163,58 @@ impl<'a> Character<'a> {
     pub fn build() -> Character<'a> {
         Character {
             edition: Edition::FifithEdition,
-            class: Class::Unknown,
+            class: vec![ClassLevel::new(Class::Unknown)],
             race: Box::new(Unknown::Unknown),
             race_usable_ap: 0,
-            race_used_ability: HashSet::new(),
+            race_used_ability: Vec::new(),
             race_used_lang: HashSet::new(),
+            race_used_skill: HashSet::new(),
+            race_skill_point: 0,
+            race_used_tools: HashSet::new(),
+            race_tool_point: 0,
+            race_used_weap: HashSet::new(),
+            race_weap_point: 0,
+            race_used_trait: HashSet::new(),
+            race_trait_point: 0,
+            background: Background::Unknown,
+            background_used_lang: HashSet::new(),
+            background_lang_point: 0,
+            background_used_tools: HashSet::new(),
+            background_tool_point: 0,
             lang_point: 0,
+            lang_rule: HashSet::from([
+                LanguageCategory::Standard,
+                LanguageCategory::Exotic
+            ]),
             lang: HashSet::new(),
+            feat: Vec::new(),
+            feat_point: 0,
+            asi: Vec::new(),
+            asi_point: 0,
+            traits: Vec::new(),
             weap: HashSet::new(),
+            weap_category: HashSet::new(),
             armor: HashSet::new(),
             skill: HashSet::new(),
+            expertise: HashSet::new(),
+            tools: HashSet::new(),
+            saving_throw: HashSet::new(),
             ap_unassigned: [0,0,0,0,0,0],
-            ap_seq: Err("Error: No sequence assigned".to_string()),
+            ap_seq: Err(CharacterError::NoSequence),
             base_ap: [0,0,0,0,0,0],
+            ap_adjustment: [0,0,0,0,0,0],
+            xp: 0,
+            hp_method: HitPointMethod::Average,
+            hp_damage: 0,
+            hp_temp: 0,
+            inventory: Vec::new(),
+            encumbrance_rule: EncumbranceRule::Standard,
+            equipped_armor: None,
+            equipped_shield: false,
             speed: 0,
             size: Size::Unknown,
-            buffer_race: None
+            buffer_race: None,
+            buffer_background: Background::Unknown.get_stat()
         }
     }
 
@@ -78,19 +232,28 @@ impl<'a> Character<'a> {
     /// ```
     /// ------------------------------------------------
     /// Refer to `role4me_lib::ed_5::race` for race enums
-    pub fn race_select<T: Race + 'a>(&mut self, race: T) -> &mut Self {
+    pub fn race_select<T: Race<Stat = Stat> + 'a>(&mut self, race: T) -> &mut Self {
         if race.as_string() != self.race.as_string() {
             self.race = Box::new(race);
             self.buffer_race = Some(self.race.get_stat());
             // Clean slate
-            self.race_used_ability = HashSet::new();
+            self.race_used_ability = Vec::new();
             self.race_used_lang = HashSet::new();
+            self.race_used_skill = HashSet::new();
+            self.race_used_tools = HashSet::new();
+            self.race_used_weap = HashSet::new();
+            self.race_used_trait = HashSet::new();
             // Initialisation
-            self.init_race_ap()
-                .init_race_lang()
-                .init_race_weap()
-                .init_race_armor()
+            self.init_race_trait()
+                .init_race_ap()
+                .init_lang()
                 .init_race_skill()
+                .init_improvement()
+                .init_weap()
+                .init_armor()
+                .init_tools()
+                .init_class_skill()
+                .init_skill()
                 .init_speed()
                 .init_size();
             // Remove race buffer if race is Unkown
@@ -117,15 +280,62 @@ impl<'a> Character<'a> {
     /// assert_eq!(player.get_ability_score(AP::INT), 1);
     /// ```
     pub fn race_use_ap(&mut self, ability: AP) -> &mut Self {
-        if self.race_usable_ap > 0 {
-            let try_insert = self.race_used_ability.insert(ability);
-            if try_insert {
-                self.init_race_ap();
-            }
-        }
+        let _ = self.try_race_use_ap(ability);
         self
     }
 
+    /// Assign points manually from race, returning the
+    /// reason when the point cannot be assigned
+    ///
+    /// Picks are matched against every bonus granted by race,
+    /// moving earlier picks to another bonus when needed
+    /// An ability receives at most one bonus unless the
+    /// bonuses stack
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::HalfElf::Basic);
+    ///
+    /// assert!(player.try_race_use_ap(AP::INT).is_ok());
+    /// // Half-Elf bonuses don't stack
+    /// assert_eq!(
+    ///     player.try_race_use_ap(AP::INT).unwrap_err(),
+    ///     ed_5::CharacterError::AbilityAlreadyAssigned(AP::INT)
+    /// );
+    /// assert_eq!(
+    ///     player.try_race_use_ap(AP::CHA).unwrap_err(),
+    ///     ed_5::CharacterError::AbilityNotAllowed(AP::CHA)
+    /// );
+    /// ```
+    pub fn try_race_use_ap(&mut self, ability: AP)
+        -> Result<&mut Self, CharacterError> {
+        if self.race_usable_ap == 0 {
+            return Err(CharacterError::NoRaceAbilityPoint);
+        }
+        let mut pick: Vec<(AP, Option<usize>)> = self.race_used_ability.iter()
+            .map(|(ap, index)| (*ap, Some(*index)))
+            .collect();
+        pick.push((ability, None));
+        match assign_ability(&self.race_ap_grant(), &pick) {
+            Some(index) => {
+                self.race_used_ability = pick.iter()
+                    .map(|(ap, _)| *ap)
+                    .zip(index)
+                    .collect();
+                Ok(self.init_race_ap())
+            },
+            None if self.race_used_ability.iter().any(|(ap, _)| *ap == ability) => {
+                Err(CharacterError::AbilityAlreadyAssigned(ability))
+            },
+            None => Err(CharacterError::AbilityNotAllowed(ability))
+        }
+    }
+
     /// Remove assigned points from race when applicable
     ///
     /// Example:
@@ -143,12 +353,38 @@ impl<'a> Character<'a> {
     /// assert_eq!(player.get_ability_score(AP::INT), 0);
     /// ```
     pub fn race_remove_ap(&mut self, ability: AP) -> &mut Self {
-        if self.race_used_ability.remove(&ability) {
-            self.init_race_ap();
-        }
+        let _ = self.try_race_remove_ap(ability);
         self
     }
 
+    /// Remove assigned points from race, returning an
+    /// error when no point was assigned to the ability
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Human::Variant);
+    ///
+    /// assert_eq!(
+    ///     player.try_race_remove_ap(AP::INT).unwrap_err(),
+    ///     ed_5::CharacterError::AbilityNotAssigned(AP::INT)
+    /// );
+    /// ```
+    pub fn try_race_remove_ap(&mut self, ability: AP)
+        -> Result<&mut Self, CharacterError> {
+        match self.race_used_ability.iter().rposition(|(ap, _)| *ap == ability) {
+            Some(index) => {
+                self.race_used_ability.remove(index);
+                Ok(self.init_race_ap())
+            },
+            None => Err(CharacterError::AbilityNotAssigned(ability))
+        }
+    }
+
     /// Remove all assigned points from race when applicable
     ///
     /// Example:
@@ -170,7 +406,7 @@ impl<'a> Character<'a> {
     /// assert_eq!(player.get_all_ability_score(), [0,0,0,0,0,0]);
     /// ```
     pub fn race_clear_ap(&mut self) -> &mut Self {
-        self.race_used_ability = HashSet::new();
+        self.race_used_ability = Vec::new();
         self.init_race_ap();
         self
     }
@@ -191,15 +427,48 @@ impl<'a> Character<'a> {
     /// assert!(player.get_all_lang().contains(&Language::Elven));
     /// ```
     pub fn race_use_lang(&mut self, language: Language) -> &mut Self {
-        if !(self.lang.contains(&language)) && (self.lang_point > 0) {
-            let try_insert = self.race_used_lang.insert(language);
-            if try_insert {
-                self.init_race_lang();
-            }
-        }
+        let _ = self.try_race_use_lang(language);
         self
     }
 
+    /// Assign language manually from race, returning the
+    /// reason when the language cannot be assigned
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Human::Variant);
+    ///
+    /// assert_eq!(
+    ///     player.try_race_use_lang(Language::Common).unwrap_err(),
+    ///     ed_5::CharacterError::LanguageKnown(Language::Common)
+    /// );
+    /// assert!(player.try_race_use_lang(Language::Elven).is_ok());
+    /// assert_eq!(
+    ///     player.try_race_use_lang(Language::Dwarven).unwrap_err(),
+    ///     ed_5::CharacterError::NoLanguagePoint
+    /// );
+    /// ```
+    pub fn try_race_use_lang(&mut self, language: Language)
+        -> Result<&mut Self, CharacterError> {
+        if self.lang.contains(&language) {
+            return Err(CharacterError::LanguageKnown(language));
+        }
+        if !self.lang_rule.contains(&language.get_category())
+            || language.get_category() == LanguageCategory::Secret {
+            return Err(CharacterError::LanguageRestricted(language));
+        }
+        if self.lang_point == 0 {
+            return Err(CharacterError::NoLanguagePoint);
+        }
+        self.race_used_lang.insert(language);
+        Ok(self.init_lang())
+    }
+
     /// Remove manually selected langauge when applicable
     ///
     /// Example:
@@ -220,12 +489,35 @@ impl<'a> Character<'a> {
     /// assert!(!player.get_all_lang().contains(&Language::Elven));
     /// ```
     pub fn race_remove_lang(&mut self, language: Language) -> &mut Self {
-        if self.race_used_lang.remove(&language) {
-            self.init_race_lang();
-        }
+        let _ = self.try_race_remove_lang(language);
         self
     }
 
+    /// Remove manually selected language, returning an
+    /// error when the language was not assigned from race
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Human::Variant);
+    ///
+    /// assert_eq!(
+    ///     player.try_race_remove_lang(Language::Common).unwrap_err(),
+    ///     ed_5::CharacterError::LanguageNotAssigned(Language::Common)
+    /// );
+    /// ```
+    pub fn try_race_remove_lang(&mut self, language: Language)
+        -> Result<&mut Self, CharacterError> {
+        if !self.race_used_lang.remove(&language) {
+            return Err(CharacterError::LanguageNotAssigned(language));
+        }
+        Ok(self.init_lang())
+    }
+
     /// Clear all manually assigned languages
     ///
     /// Example:
@@ -247,195 +539,201 @@ impl<'a> Character<'a> {
     /// ```
     pub fn race_clear_lang(&mut self) -> &mut Self {
         self.race_used_lang = HashSet::new();
-        self.init_race_lang();
+        self.init_lang();
         self
     }
 
-    /// Use roll method to generate base stat
+    /// Select language categories that language points
+    /// from race and background can be spent on, dropping
+    /// assigned languages no longer allowed
+    /// Secret languages are only taught by class
     ///
     /// Example:
     /// ```
     /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
     ///
     /// let mut player = ed_5::Character::build();
     ///
-    /// player.ap_dice_roll();
+    /// player
+    ///     .race_select(ed_5::Human::Variant)
+    ///     .race_use_lang(Language::Infernal);
+    ///
+    /// assert!(player.get_all_lang().contains(&Language::Infernal));
+    ///
+    /// player.lang_rule_select(vec![ed_5::LanguageCategory::Standard]);
+    ///
+    /// assert!(!player.get_all_lang().contains(&Language::Infernal));
+    /// assert_eq!(
+    ///     player.try_race_use_lang(Language::Infernal).unwrap_err(),
+    ///     ed_5::CharacterError::LanguageRestricted(Language::Infernal)
+    /// );
     /// ```
-    pub fn ap_dice_roll(&mut self) -> &mut Self {
-        self.ap_unassigned = dice::roll();
-        // Reset ap_seq
-        self.ap_seq = Err("Error: No sequence assigned".to_string());
+    pub fn lang_rule_select(&mut self, category: Vec<LanguageCategory>) -> &mut Self {
+        self.lang_rule = category.into_iter()
+            .filter(|category| *category != LanguageCategory::Secret)
+            .collect();
+        let rule = &self.lang_rule;
+        self.race_used_lang.retain(|lang| rule.contains(&lang.get_category()));
+        self.background_used_lang.retain(|lang| rule.contains(&lang.get_category()));
+        self.init_lang();
         self
     }
 
-    /// Use Standard Array for base stat
-    /// Follow up of ap_assign_seq() method required 
+    /// Assign skill manually from race when applicable
     ///
     /// Example:
     /// ```
     /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
     ///
     /// let mut player = ed_5::Character::build();
     ///
-    /// player.ap_standard_array();
+    /// player
+    ///     .race_select(ed_5::Human::Variant)
+    ///     .race_use_skill(Skill::Athletics);
     ///
-    /// assert_eq!(player.get_ap_unassigned(), &[15,14,13,12,10,8]);
+    /// assert!(player.get_all_skill().contains(&Skill::Athletics));
+    /// assert_eq!(player.get_race_unused_skill(), &0);
     /// ```
-    pub fn ap_standard_array(&mut self) -> &mut Self {
-        self.ap_unassigned = [15,14,13,12,10,8];
-        // Reset ap_seq
-        self.ap_seq = Err("Error: No sequence assigned".to_string());
+    pub fn race_use_skill(&mut self, skill: Skill) -> &mut Self {
+        let _ = self.try_race_use_skill(skill);
         self
     }
 
-    /// Use Heroic Array for base stat
-    /// Follow up of ap_assign_seq() method required 
+    /// Assign skill manually from race, returning the
+    /// reason when the skill cannot be assigned
     ///
     /// Example:
     /// ```
     /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
     ///
     /// let mut player = ed_5::Character::build();
     ///
-    /// player.ap_heroic_array();
+    /// player.race_select(ed_5::HalfElf::Basic);
     ///
-    /// assert_eq!(player.get_ap_unassigned(), &[17,16,14,14,12,10]);
+    /// assert!(player.try_race_use_skill(Skill::Stealth).is_ok());
+    /// assert_eq!(
+    ///     player.try_race_use_skill(Skill::Stealth).unwrap_err(),
+    ///     ed_5::CharacterError::SkillKnown(Skill::Stealth)
+    /// );
+    /// assert!(player.try_race_use_skill(Skill::Insight).is_ok());
+    /// assert_eq!(
+    ///     player.try_race_use_skill(Skill::Arcana).unwrap_err(),
+    ///     ed_5::CharacterError::NoRaceSkillPoint
+    /// );
     /// ```
-    pub fn ap_heroic_array(&mut self) -> &mut Self {
-        self.ap_unassigned = [17,16,14,14,12,10];
-        // Reset ap_seq
-        self.ap_seq = Err("Error: No sequence assigned".to_string());
-        self
+    pub fn try_race_use_skill(&mut self, skill: Skill)
+        -> Result<&mut Self, CharacterError> {
+        if self.skill.contains(&skill) {
+            return Err(CharacterError::SkillKnown(skill));
+        }
+        let choice = self.race_choice(RaceChoice::get_skill);
+        if self.race_used_skill.len() >= choice.len() {
+            return Err(CharacterError::NoRaceSkillPoint);
+        }
+        if !fits_choice(&choice, self.race_used_skill.iter().chain([&skill])) {
+            return Err(CharacterError::SkillNotAvailable(skill));
+        }
+        self.race_used_skill.insert(skill);
+        Ok(self.init_race_skill().init_skill())
     }
 
-    /// Use point buy method for base AP
-    /// 
+    /// Remove skill manually selected from race
+    ///
     /// Example:
     /// ```
     /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
     ///
     /// let mut player = ed_5::Character::build();
     ///
-    /// player.ap_point_buy([8,13,14,15,12,10]);
+    /// player
+    ///     .race_select(ed_5::Human::Variant)
+    ///     .race_use_skill(Skill::Athletics)
+    ///     .race_remove_skill(Skill::Athletics);
     ///
-    /// assert_eq!(player.get_all_ability_score(), [8,13,14,15,12,10]);
+    /// assert!(!player.get_all_skill().contains(&Skill::Athletics));
     /// ```
-    pub fn ap_point_buy(&mut self, points: [usize; 6]) -> &mut Self {
-        for point in points {
-            if !(8..=15).contains(&point) {
-                return self
-            }
-        }
-        self.ap_unassigned = points;
-        self.ap_assign_seq([0,1,2,3,4,5]);
-        // Reset ap_seq
-        self.ap_seq = Err("Error: No sequence assigned".to_string());
+    pub fn race_remove_skill(&mut self, skill: Skill) -> &mut Self {
+        let _ = self.try_race_remove_skill(skill);
         self
     }
 
-    /// Return the remainder from point buy if applicable
-    /// Error returns a string that can be printed
-    /// for debugging
+    /// Remove skill manually selected from race, returning
+    /// an error when the skill was not assigned
     ///
     /// Example:
     /// ```
     /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
     ///
     /// let mut player = ed_5::Character::build();
     ///
-    /// assert_eq!(
-    /// player.ap_check_point_buy([8,13,14,15,12,10]), Ok(0) 
-    /// );
+    /// player.race_select(ed_5::Human::Variant);
     ///
     /// assert_eq!(
-    /// player.ap_check_point_buy([10,10,10,10,10,10]), Ok(15)
+    ///     player.try_race_remove_skill(Skill::Athletics).unwrap_err(),
+    ///     ed_5::CharacterError::SkillNotAssigned(Skill::Athletics)
     /// );
     /// ```
-    /// -----------------------------------------------------
-    /// Refer to D&D rules for more information
-    /// regarding point buy.
-    pub fn ap_check_point_buy(&mut self, points: [usize; 6]) -> Result<usize, String> {
-        let mut sum: usize = 0;
-        for point in points {
-            // Check if point within rule's limit
-            if (8..=15).contains(&point) {
-                let x = point - 8;
-                let b = (x-(x%5))/5; // Match magic, try big brain it yourself
-                sum += x + (b * x%5);
-                println!("{}", x + (b * x%5));
-            }
-            else {
-                return Err(format!("Error: Point must be within 8 & 15\n\
-                            Current input: {:?}", points));
-            }
-        }
-        println!("{:?}", points);
-        if sum <= 27 {
-            Ok(27-sum)
-        }
-        else {
-            Err(format!("Error: Points sum exceeded limit, \
-                    Limit: 27 Assigned: {}", sum))
+    pub fn try_race_remove_skill(&mut self, skill: Skill)
+        -> Result<&mut Self, CharacterError> {
+        if !self.race_used_skill.remove(&skill) {
+            return Err(CharacterError::SkillNotAssigned(skill));
         }
+        Ok(self.init_race_skill().init_skill())
     }
 
-    /// Assign sequence to rolled stat
-    /// Important:
-    /// Value must start from 0, and ends at 5
+    /// Clear all skills assigned from race
     ///
     /// Example:
     /// ```
     /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
     ///
     /// let mut player = ed_5::Character::build();
     ///
     /// player
-    ///     .ap_dice_roll()
-    ///     .ap_assign_seq([2,1,3,5,4,0]);
+    ///     .race_select(ed_5::HalfElf::Basic)
+    ///     .race_use_skill(Skill::Stealth)
+    ///     .race_use_skill(Skill::Insight)
+    ///     .race_clear_skill();
+    ///
+    /// assert!(player.get_all_skill().is_empty());
+    /// assert_eq!(player.get_race_unused_skill(), &2);
     /// ```
-    /// ---------------------------------------
-    /// From the above example
-    /// If rolled stat is [2,4,6,8,10,12]
-    /// Rolled stat will be [12,4,2,6,10,8]
-    pub fn ap_assign_seq(&mut self, sequence: [usize; 6])
-        -> &mut Self {
-        let mut set = BTreeSet::new();
-        for val in sequence {
-            if val < 6 {set.insert(val);}
-        }
-        if set.len() == 6 {
-            self.ap_seq = Ok(set);
-        }
-        else {
-            self.ap_seq = Err(
-                format!("Error: Assigned sequence does not \
-                    meet requirement. Sequence: {:?}\n\
-                    All value must be between 0 and 5 with \
-                    no repetition.", sequence)
-                );
-        }
-        self.init_base_ap()
+    pub fn race_clear_skill(&mut self) -> &mut Self {
+        self.race_used_skill = HashSet::new();
+        self.init_race_skill().init_skill();
+        self
     }
 
-    /// Select/Change character class
+    /// Assign tool proficiency manually from race when
+    /// applicable
     ///
-    /// Example: 
+    /// Example:
     /// ```
     /// use role4me_lib::ed_5;
     /// use role4me_lib::prelude::*;
     ///
     /// let mut player = ed_5::Character::build();
     ///
-    /// player.class_select(ed_5::Class::Cleric);
+    /// player
+    ///     .race_select(ed_5::Dwarf::Hill)
+    ///     .race_use_tool(Tools::SmithsTools);
     ///
-    /// assert_eq!(player.get_class(), &ed_5::Class::Cleric);
+    /// assert!(player.get_all_tools().contains(&Tools::SmithsTools));
+    /// assert_eq!(player.get_race_unused_tool(), &0);
     /// ```
-    pub fn class_select(&mut self, class: Class) -> &mut Self {
-        self.class = class;
+    pub fn race_use_tool(&mut self, tool: Tools) -> &mut Self {
+        let _ = self.try_race_use_tool(tool);
         self
     }
 
-    /// Return race name as String
+    /// Assign tool proficiency manually from race, returning
+    /// the reason when the tool cannot be assigned
     ///
     /// Example:
     /// ```
@@ -444,14 +742,27 @@ impl<'a> Character<'a> {
     ///
     /// let mut player = ed_5::Character::build();
     ///
-    /// assert_eq!(player.get_race(), "Unknown(Unknown)".to_string());
+    /// player.race_select(ed_5::Dwarf::Mountain);
+    ///
+    /// assert_eq!(
+    ///     player.try_race_use_tool(Tools::TinkersTools).unwrap_err(),
+    ///     ed_5::CharacterError::ToolNotAvailable(Tools::TinkersTools)
+    /// );
+    /// assert!(player.try_race_use_tool(Tools::MasonsTools).is_ok());
+    /// assert_eq!(
+    ///     player.try_race_use_tool(Tools::BrewersSupplies).unwrap_err(),
+    ///     ed_5::CharacterError::NoToolPoint
+    /// );
     /// ```
-    pub fn get_race(&self) -> String {
-        self.race.as_string()
+    pub fn try_race_use_tool(&mut self, tool: Tools)
+        -> Result<&mut Self, CharacterError> {
+        let choice = self.race_choice(RaceChoice::get_tools);
+        self.check_tool_choice(&choice, &self.race_used_tools, &tool)?;
+        self.race_used_tools.insert(tool);
+        Ok(self.init_tools())
     }
 
-    /// Return refernce to value of assignable ability
-    /// score gained from certain race
+    /// Remove tool proficiency manually assigned from race
     ///
     /// Example:
     /// ```
@@ -460,20 +771,20 @@ impl<'a> Character<'a> {
     ///
     /// let mut player = ed_5::Character::build();
     ///
-    /// player.race_select(ed_5::Human::Variant);
-    ///
-    /// assert_eq!(player.get_race_unused_ap(), &2);
-    ///
-    /// player.race_use_ap(AP::STR);
+    /// player
+    ///     .race_select(ed_5::Dwarf::Hill)
+    ///     .race_use_tool(Tools::SmithsTools)
+    ///     .race_remove_tool(Tools::SmithsTools);
     ///
-    /// assert_eq!(player.get_race_unused_ap(), &1);
+    /// assert!(player.get_all_tools().is_empty());
     /// ```
-    pub fn get_race_unused_ap(&self) -> &usize {
-        &self.race_usable_ap
+    pub fn race_remove_tool(&mut self, tool: Tools) -> &mut Self {
+        let _ = self.try_race_remove_tool(tool);
+        self
     }
 
-    /// Return value of specific ability score from
-    /// the final/total caculated ability scores
+    /// Remove tool proficiency manually assigned from race,
+    /// returning an error when it was not assigned
     ///
     /// Example:
     /// ```
@@ -482,20 +793,22 @@ impl<'a> Character<'a> {
     ///
     /// let mut player = ed_5::Character::build();
     ///
-    /// player
-    ///     .race_select(ed_5::Human::Variant)
-    ///     .race_use_ap(AP::INT);
-    ///
-    /// let val = player.get_ability_score(AP::INT);
+    /// player.race_select(ed_5::Gnome::Rock);
     ///
-    /// assert_eq!(val, 1);
+    /// assert_eq!(
+    ///     player.try_race_remove_tool(Tools::TinkersTools).unwrap_err(),
+    ///     ed_5::CharacterError::ToolNotAssigned(Tools::TinkersTools)
+    /// );
     /// ```
-    pub fn get_ability_score(&self, ap: AP) -> usize {
-        let scores = self.get_all_ability_score();
-        scores[ap.get_index()]
+    pub fn try_race_remove_tool(&mut self, tool: Tools)
+        -> Result<&mut Self, CharacterError> {
+        if !self.race_used_tools.remove(&tool) {
+            return Err(CharacterError::ToolNotAssigned(tool));
+        }
+        Ok(self.init_tools())
     }
 
-    /// Calculate and return the total ability scores
+    /// Clear all tool proficiencies assigned from race
     ///
     /// Example:
     /// ```
@@ -505,28 +818,3550 @@ impl<'a> Character<'a> {
     /// let mut player = ed_5::Character::build();
     ///
     /// player
-    ///     .race_select(ed_5::Human::Variant)
-    ///     .race_use_ap(AP::INT);
+    ///     .race_select(ed_5::Dwarf::Hill)
+    ///     .race_use_tool(Tools::BrewersSupplies)
+    ///     .race_clear_tool();
     ///
-    /// let val = player.get_all_ability_score();
-    ///
-    /// assert_eq!(val, [0,0,0,1,0,0]);
+    /// assert_eq!(player.get_race_unused_tool(), &1);
     /// ```
-    pub fn get_all_ability_score(&self) -> [usize; 6] {
-        let mut ability_scores = [0,0,0,0,0,0];
-        self.calculate_race_default(&mut ability_scores);
-        self.calculate_race_user(&mut ability_scores);
-        self.calculate_base_ap(&mut ability_scores);
-        ability_scores
+    pub fn race_clear_tool(&mut self) -> &mut Self {
+        self.race_used_tools = HashSet::new();
+        self.init_tools();
+        self
     }
 
-    /// Return reference to a HashSet of known languages
+    /// Assign weapon proficiency manually from race when
+    /// applicable
     ///
     /// Example:
     /// ```
     /// use role4me_lib::ed_5;
     /// use role4me_lib::prelude::*;
-    /// use std::collections::HashSet;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// // No race of the catalogue offers a weapon choice
+    /// player
+    ///     .race_select(ed_5::Elf::Wood)
+    ///     .race_use_weap(Weapon::Rapier);
+    ///
+    /// assert!(!player.is_proficient(Weapon::Rapier));
+    /// assert_eq!(player.get_race_unused_weap(), &0);
+    /// ```
+    pub fn race_use_weap(&mut self, weapon: Weapon) -> &mut Self {
+        let _ = self.try_race_use_weap(weapon);
+        self
+    }
+
+    /// Assign weapon proficiency manually from race,
+    /// returning the reason when the weapon cannot be assigned
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Human::Basic);
+    ///
+    /// assert_eq!(
+    ///     player.try_race_use_weap(Weapon::Longbow).unwrap_err(),
+    ///     ed_5::CharacterError::NoWeaponPoint
+    /// );
+    /// ```
+    pub fn try_race_use_weap(&mut self, weapon: Weapon)
+        -> Result<&mut Self, CharacterError> {
+        if self.weap.contains(&weapon) {
+            return Err(CharacterError::WeaponKnown(weapon));
+        }
+        let choice = self.race_choice(RaceChoice::get_weapon);
+        if self.race_used_weap.len() >= choice.len() {
+            return Err(CharacterError::NoWeaponPoint);
+        }
+        if !fits_choice(&choice, self.race_used_weap.iter().chain([&weapon])) {
+            return Err(CharacterError::WeaponNotAvailable(weapon));
+        }
+        self.race_used_weap.insert(weapon);
+        Ok(self.init_weap())
+    }
+
+    /// Remove weapon proficiency manually assigned from race
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Basic)
+    ///     .race_remove_weap(Weapon::Longbow);
+    ///
+    /// assert!(player.get_all_weap().is_empty());
+    /// ```
+    pub fn race_remove_weap(&mut self, weapon: Weapon) -> &mut Self {
+        let _ = self.try_race_remove_weap(weapon);
+        self
+    }
+
+    /// Remove weapon proficiency manually assigned from race,
+    /// returning an error when it was not assigned
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Elf::High);
+    ///
+    /// assert_eq!(
+    ///     player.try_race_remove_weap(Weapon::Longbow).unwrap_err(),
+    ///     ed_5::CharacterError::WeaponNotAssigned(Weapon::Longbow)
+    /// );
+    /// ```
+    pub fn try_race_remove_weap(&mut self, weapon: Weapon)
+        -> Result<&mut Self, CharacterError> {
+        if !self.race_used_weap.remove(&weapon) {
+            return Err(CharacterError::WeaponNotAssigned(weapon));
+        }
+        Ok(self.init_weap())
+    }
+
+    /// Clear all weapon proficiencies assigned from race
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Basic)
+    ///     .race_clear_weap();
+    ///
+    /// assert_eq!(player.get_race_unused_weap(), &0);
+    /// ```
+    pub fn race_clear_weap(&mut self) -> &mut Self {
+        self.race_used_weap = HashSet::new();
+        self.init_weap()
+    }
+
+    /// Pick racial trait offered as a race option, such as
+    /// draconic ancestry
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Dragonborn::Basic)
+    ///     .race_use_trait(ed_5::RacialTrait::DraconicAncestryRed);
+    ///
+    /// assert_eq!(player.get_all_resistance(), HashSet::from([DamageType::Fire]));
+    /// assert_eq!(player.get_race_unused_trait(), &0);
+    /// ```
+    pub fn race_use_trait(&mut self, racial_trait: RacialTrait) -> &mut Self {
+        let _ = self.try_race_use_trait(racial_trait);
+        self
+    }
+
+    /// Pick racial trait offered as a race option, returning
+    /// the reason when the trait cannot be picked
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Dragonborn::Basic);
+    ///
+    /// assert_eq!(
+    ///     player.try_race_use_trait(ed_5::RacialTrait::Darkvision).unwrap_err(),
+    ///     ed_5::CharacterError::TraitNotAvailable(ed_5::RacialTrait::Darkvision)
+    /// );
+    /// assert!(player.try_race_use_trait(ed_5::RacialTrait::DraconicAncestryGold).is_ok());
+    /// assert_eq!(
+    ///     player.try_race_use_trait(ed_5::RacialTrait::DraconicAncestryRed).unwrap_err(),
+    ///     ed_5::CharacterError::NoTraitPoint
+    /// );
+    /// ```
+    pub fn try_race_use_trait(&mut self, racial_trait: RacialTrait)
+        -> Result<&mut Self, CharacterError> {
+        if self.traits.contains(&racial_trait) {
+            return Err(CharacterError::TraitKnown(racial_trait));
+        }
+        let choice = self.race_choice(RaceChoice::get_trait);
+        if self.race_used_trait.len() >= choice.len() {
+            return Err(CharacterError::NoTraitPoint);
+        }
+        if !fits_choice(&choice, self.race_used_trait.iter().chain([&racial_trait])) {
+            return Err(CharacterError::TraitNotAvailable(racial_trait));
+        }
+        self.race_used_trait.insert(racial_trait);
+        Ok(self.init_race_trait())
+    }
+
+    /// Remove racial trait picked as a race option
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Dragonborn::Basic)
+    ///     .race_use_trait(ed_5::RacialTrait::DraconicAncestryRed)
+    ///     .race_remove_trait(ed_5::RacialTrait::DraconicAncestryRed);
+    ///
+    /// assert!(player.get_all_traits().is_empty());
+    /// ```
+    pub fn race_remove_trait(&mut self, racial_trait: RacialTrait) -> &mut Self {
+        let _ = self.try_race_remove_trait(racial_trait);
+        self
+    }
+
+    /// Remove racial trait picked as a race option,
+    /// returning an error when it was not picked
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Tiefling::Basic);
+    ///
+    /// assert_eq!(
+    ///     player.try_race_remove_trait(ed_5::RacialTrait::Darkvision).unwrap_err(),
+    ///     ed_5::CharacterError::TraitNotAssigned(ed_5::RacialTrait::Darkvision)
+    /// );
+    /// ```
+    pub fn try_race_remove_trait(&mut self, racial_trait: RacialTrait)
+        -> Result<&mut Self, CharacterError> {
+        if !self.race_used_trait.remove(&racial_trait) {
+            return Err(CharacterError::TraitNotAssigned(racial_trait));
+        }
+        Ok(self.init_race_trait())
+    }
+
+    /// Clear all racial traits picked as race options
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Dragonborn::Basic)
+    ///     .race_use_trait(ed_5::RacialTrait::DraconicAncestryRed)
+    ///     .race_clear_trait();
+    ///
+    /// assert_eq!(player.get_race_unused_trait(), &1);
+    /// ```
+    pub fn race_clear_trait(&mut self) -> &mut Self {
+        self.race_used_trait = HashSet::new();
+        self.init_race_trait()
+    }
+
+    /// Select/change character background
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.background_select(ed_5::Background::Criminal);
+    ///
+    /// assert!(player.get_all_skill().contains(&Skill::Stealth));
+    /// assert!(player.get_all_tools().contains(&Tools::TheivesTools));
+    /// ```
+    /// ------------------------------------------------------------
+    /// Refer to `role4me_lib::ed_5::CustomBackground` for custom
+    /// backgrounds
+    pub fn background_select(&mut self, background: Background) -> &mut Self {
+        if background != self.background {
+            self.buffer_background = background.get_stat();
+            self.background = background;
+            // Clean slate
+            self.background_used_lang = HashSet::new();
+            self.background_used_tools = HashSet::new();
+            // Initialisation
+            self.init_lang()
+                .init_tools()
+                .init_race_skill()
+                .init_class_skill()
+                .init_skill();
+        }
+        self
+    }
+
+    /// Assign language manually from background when applicable
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .background_select(ed_5::Background::Sage)
+    ///     .background_use_lang(Language::Draconic);
+    ///
+    /// assert!(player.get_all_lang().contains(&Language::Draconic));
+    /// assert_eq!(player.get_background_unused_lang(), &1);
+    /// ```
+    pub fn background_use_lang(&mut self, language: Language) -> &mut Self {
+        let _ = self.try_background_use_lang(language);
+        self
+    }
+
+    /// Assign language manually from background, returning
+    /// the reason when the language cannot be assigned
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Elf::High)
+    ///     .background_select(ed_5::Background::Hermit);
+    ///
+    /// assert_eq!(
+    ///     player.try_background_use_lang(Language::Elven).unwrap_err(),
+    ///     ed_5::CharacterError::LanguageKnown(Language::Elven)
+    /// );
+    /// assert!(player.try_background_use_lang(Language::Orc).is_ok());
+    /// assert_eq!(
+    ///     player.try_background_use_lang(Language::Infernal).unwrap_err(),
+    ///     ed_5::CharacterError::NoLanguagePoint
+    /// );
+    /// ```
+    pub fn try_background_use_lang(&mut self, language: Language)
+        -> Result<&mut Self, CharacterError> {
+        if self.lang.contains(&language) {
+            return Err(CharacterError::LanguageKnown(language));
+        }
+        if !self.lang_rule.contains(&language.get_category())
+            || language.get_category() == LanguageCategory::Secret {
+            return Err(CharacterError::LanguageRestricted(language));
+        }
+        if self.background_lang_point == 0 {
+            return Err(CharacterError::NoLanguagePoint);
+        }
+        self.background_used_lang.insert(language);
+        Ok(self.init_lang())
+    }
+
+    /// Remove language manually selected from background
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .background_select(ed_5::Background::Acolyte)
+    ///     .background_use_lang(Language::Elven)
+    ///     .background_remove_lang(Language::Elven);
+    ///
+    /// assert!(!player.get_all_lang().contains(&Language::Elven));
+    /// ```
+    pub fn background_remove_lang(&mut self, language: Language) -> &mut Self {
+        let _ = self.try_background_remove_lang(language);
+        self
+    }
+
+    /// Remove language manually selected from background,
+    /// returning an error when it was not assigned
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.background_select(ed_5::Background::Acolyte);
+    ///
+    /// assert_eq!(
+    ///     player.try_background_remove_lang(Language::Elven).unwrap_err(),
+    ///     ed_5::CharacterError::LanguageNotAssigned(Language::Elven)
+    /// );
+    /// ```
+    pub fn try_background_remove_lang(&mut self, language: Language)
+        -> Result<&mut Self, CharacterError> {
+        if !self.background_used_lang.remove(&language) {
+            return Err(CharacterError::LanguageNotAssigned(language));
+        }
+        Ok(self.init_lang())
+    }
+
+    /// Clear all languages assigned from background
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .background_select(ed_5::Background::Acolyte)
+    ///     .background_use_lang(Language::Elven)
+    ///     .background_use_lang(Language::Orc)
+    ///     .background_clear_lang();
+    ///
+    /// assert!(player.get_all_lang().is_empty());
+    /// assert_eq!(player.get_background_unused_lang(), &2);
+    /// ```
+    pub fn background_clear_lang(&mut self) -> &mut Self {
+        self.background_used_lang = HashSet::new();
+        self.init_lang();
+        self
+    }
+
+    /// Assign tool manually from background when applicable
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .background_select(ed_5::Background::FolkHero)
+    ///     .background_use_tool(Tools::TinkersTools);
+    ///
+    /// assert!(player.get_all_tools().contains(&Tools::TinkersTools));
+    /// assert_eq!(player.get_background_unused_tool(), &0);
+    /// ```
+    pub fn background_use_tool(&mut self, tool: Tools) -> &mut Self {
+        let _ = self.try_background_use_tool(tool);
+        self
+    }
+
+    /// Assign tool manually from background, returning
+    /// the reason when the tool cannot be assigned
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.background_select(ed_5::Background::Criminal);
+    ///
+    /// assert_eq!(
+    ///     player.try_background_use_tool(Tools::TheivesTools).unwrap_err(),
+    ///     ed_5::CharacterError::ToolKnown(Tools::TheivesTools)
+    /// );
+    /// assert_eq!(
+    ///     player.try_background_use_tool(Tools::ForgeryKit).unwrap_err(),
+    ///     ed_5::CharacterError::ToolNotAvailable(Tools::ForgeryKit)
+    /// );
+    /// assert!(player.try_background_use_tool(Tools::DiceSet).is_ok());
+    /// assert_eq!(
+    ///     player.try_background_use_tool(Tools::PlayingCardSet).unwrap_err(),
+    ///     ed_5::CharacterError::NoToolPoint
+    /// );
+    /// ```
+    pub fn try_background_use_tool(&mut self, tool: Tools)
+        -> Result<&mut Self, CharacterError> {
+        let choice = tool_option(&self.buffer_background.tool_choice);
+        self.check_tool_choice(&choice, &self.background_used_tools, &tool)?;
+        self.background_used_tools.insert(tool);
+        Ok(self.init_tools())
+    }
+
+    /// Remove tool manually selected from background
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .background_select(ed_5::Background::Noble)
+    ///     .background_use_tool(Tools::DragonchessSet)
+    ///     .background_remove_tool(Tools::DragonchessSet);
+    ///
+    /// assert!(player.get_all_tools().is_empty());
+    /// ```
+    pub fn background_remove_tool(&mut self, tool: Tools) -> &mut Self {
+        let _ = self.try_background_remove_tool(tool);
+        self
+    }
+
+    /// Remove tool manually selected from background,
+    /// returning an error when it was not assigned
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.background_select(ed_5::Background::Urchin);
+    ///
+    /// assert_eq!(
+    ///     player.try_background_remove_tool(Tools::TheivesTools).unwrap_err(),
+    ///     ed_5::CharacterError::ToolNotAssigned(Tools::TheivesTools)
+    /// );
+    /// ```
+    pub fn try_background_remove_tool(&mut self, tool: Tools)
+        -> Result<&mut Self, CharacterError> {
+        if !self.background_used_tools.remove(&tool) {
+            return Err(CharacterError::ToolNotAssigned(tool));
+        }
+        Ok(self.init_tools())
+    }
+
+    /// Clear all tools assigned from background
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .background_select(ed_5::Background::Noble)
+    ///     .background_use_tool(Tools::DragonchessSet)
+    ///     .background_clear_tool();
+    ///
+    /// assert_eq!(player.get_background_unused_tool(), &1);
+    /// ```
+    pub fn background_clear_tool(&mut self) -> &mut Self {
+        self.background_used_tools = HashSet::new();
+        self.init_tools();
+        self
+    }
+
+    /// Take a feat using a feat slot from race or from
+    /// an ability score improvement level
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Variant)
+    ///     .ap_point_buy([8,14,14,15,10,8])
+    ///     .feat_use(ed_5::Feat::Alert);
+    ///
+    /// assert_eq!(player.get_all_feat(), &[ed_5::Feat::Alert]);
+    /// // DEX modifier (2) + Alert (5)
+    /// assert_eq!(player.get_initiative(), 7);
+    /// ```
+    pub fn feat_use(&mut self, feat: Feat) -> &mut Self {
+        let _ = self.try_feat_use(feat);
+        self
+    }
+
+    /// Take a feat, returning the reason when the feat
+    /// cannot be taken
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// assert_eq!(
+    ///     player.try_feat_use(ed_5::Feat::Tough).unwrap_err(),
+    ///     ed_5::CharacterError::NoFeatPoint
+    /// );
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Variant)
+    ///     .ap_point_buy([8,14,14,15,10,8]);
+    ///
+    /// assert_eq!(
+    ///     player.try_feat_use(ed_5::Feat::Observant(AP::STR)).unwrap_err(),
+    ///     ed_5::CharacterError::FeatInvalid(ed_5::Feat::Observant(AP::STR))
+    /// );
+    /// assert_eq!(
+    ///     player.try_feat_use(ed_5::Feat::Grappler).unwrap_err(),
+    ///     ed_5::CharacterError::FeatRequirement {
+    ///         feat: ed_5::Feat::Grappler,
+    ///         prerequisite: ed_5::FeatPrerequisite::Ability(vec![(AP::STR, 13)])
+    ///     }
+    /// );
+    /// assert!(player.try_feat_use(ed_5::Feat::Resilient(AP::CON)).is_ok());
+    /// assert!(player.get_all_saving_throw().contains(&AP::CON));
+    /// ```
+    pub fn try_feat_use(&mut self, feat: Feat)
+        -> Result<&mut Self, CharacterError> {
+        if !feat.is_valid() {
+            return Err(CharacterError::FeatInvalid(feat));
+        }
+        if self.feat.iter().any(|known| known.get_name() == feat.get_name()) {
+            return Err(CharacterError::FeatKnown(feat));
+        }
+        if self.feat_point == 0 {
+            return Err(CharacterError::NoFeatPoint);
+        }
+        self.check_feat_prerequisite(&feat)?;
+        for effect in feat.get_effect() {
+            if let FeatEffect::SkillProf(skill) = effect {
+                if self.skill.contains(&skill) {
+                    return Err(CharacterError::SkillKnown(skill));
+                }
+            }
+        }
+        self.feat.push(feat);
+        Ok(self.init_improvement())
+    }
+
+    /// Remove a feat, freeing its slot
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Variant)
+    ///     .feat_use(ed_5::Feat::Mobile)
+    ///     .feat_remove(ed_5::Feat::Mobile);
+    ///
+    /// assert!(player.get_all_feat().is_empty());
+    /// assert_eq!(player.get_feat_unused(), &1);
+    /// ```
+    pub fn feat_remove(&mut self, feat: Feat) -> &mut Self {
+        let _ = self.try_feat_remove(feat);
+        self
+    }
+
+    /// Remove a feat, returning an error when the feat
+    /// has not been taken
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// assert_eq!(
+    ///     player.try_feat_remove(ed_5::Feat::Lucky).unwrap_err(),
+    ///     ed_5::CharacterError::FeatNotTaken(ed_5::Feat::Lucky)
+    /// );
+    /// ```
+    pub fn try_feat_remove(&mut self, feat: Feat)
+        -> Result<&mut Self, CharacterError> {
+        match self.feat.iter().position(|known| known == &feat) {
+            Some(index) => self.feat.remove(index),
+            None => return Err(CharacterError::FeatNotTaken(feat))
+        };
+        Ok(self.init_improvement())
+    }
+
+    /// Remove every feat taken
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Variant)
+    ///     .feat_use(ed_5::Feat::Lucky)
+    ///     .feat_clear();
+    ///
+    /// assert!(player.get_all_feat().is_empty());
+    /// ```
+    pub fn feat_clear(&mut self) -> &mut Self {
+        self.feat = Vec::new();
+        self.init_improvement();
+        self
+    }
+
+    /// Spend an ability score improvement gained from
+    /// class levels, +2 to one ability or +1 to two
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .ap_point_buy([15,14,13,12,10,8])
+    ///     .class_select(ed_5::Class::Fighter);
+    /// player.add_xp(2700);
+    /// player.asi_use(ed_5::AbilityImprovement::Single(AP::STR));
+    ///
+    /// assert_eq!(player.get_ability_score(AP::STR), 17);
+    /// ```
+    pub fn asi_use(&mut self, improvement: AbilityImprovement) -> &mut Self {
+        let _ = self.try_asi_use(improvement);
+        self
+    }
+
+    /// Spend an ability score improvement, returning the
+    /// reason when it cannot be spent
+    ///
+    /// Scores can't be raised above 20
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Variant)
+    ///     .ap_point_buy([15,14,13,12,10,8])
+    ///     .class_select(ed_5::Class::Fighter);
+    ///
+    /// // Variant Human feat slot can't be spent on abilities
+    /// assert_eq!(
+    ///     player.try_asi_use(ed_5::AbilityImprovement::Single(AP::STR)).unwrap_err(),
+    ///     ed_5::CharacterError::NoAsiPoint
+    /// );
+    ///
+    /// player.add_xp(2700);
+    ///
+    /// assert_eq!(
+    ///     player.try_asi_use(ed_5::AbilityImprovement::Split(AP::DEX, AP::DEX)).unwrap_err(),
+    ///     ed_5::CharacterError::AsiSameAbility(AP::DEX)
+    /// );
+    /// assert!(player.try_asi_use(ed_5::AbilityImprovement::Split(AP::DEX, AP::CON)).is_ok());
+    /// assert_eq!(player.get_ability_score(AP::DEX), 15);
+    /// ```
+    pub fn try_asi_use(&mut self, improvement: AbilityImprovement)
+        -> Result<&mut Self, CharacterError> {
+        if let AbilityImprovement::Split(first, second) = improvement {
+            if first == second {
+                return Err(CharacterError::AsiSameAbility(first));
+            }
+        }
+        if self.asi_point == 0 {
+            return Err(CharacterError::NoAsiPoint);
+        }
+        // Cap applies before adjustments from items and effects
+        let scores = self.unadjusted_ability_score();
+        let increase = improvement.get_ability();
+        for ap in &increase {
+            let count = increase.iter().filter(|other| *other == ap).count() as isize;
+            if scores[ap.get_index()] + count > 20 {
+                return Err(CharacterError::AbilityScoreMax(*ap));
+            }
+        }
+        self.asi.push(improvement);
+        Ok(self.init_improvement())
+    }
+
+    /// Undo a spent ability score improvement
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .ap_point_buy([15,14,13,12,10,8])
+    ///     .class_select(ed_5::Class::Fighter);
+    /// player.add_xp(2700);
+    ///
+    /// player
+    ///     .asi_use(ed_5::AbilityImprovement::Single(AP::STR))
+    ///     .asi_remove(ed_5::AbilityImprovement::Single(AP::STR));
+    ///
+    /// assert_eq!(player.get_ability_score(AP::STR), 15);
+    /// assert_eq!(player.get_asi_unused(), &1);
+    /// ```
+    pub fn asi_remove(&mut self, improvement: AbilityImprovement) -> &mut Self {
+        let _ = self.try_asi_remove(improvement);
+        self
+    }
+
+    /// Undo a spent ability score improvement, returning an
+    /// error when it has not been spent
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// assert_eq!(
+    ///     player.try_asi_remove(ed_5::AbilityImprovement::Single(AP::STR)).unwrap_err(),
+    ///     ed_5::CharacterError::AsiNotTaken(ed_5::AbilityImprovement::Single(AP::STR))
+    /// );
+    /// ```
+    pub fn try_asi_remove(&mut self, improvement: AbilityImprovement)
+        -> Result<&mut Self, CharacterError> {
+        match self.asi.iter().position(|spent| spent == &improvement) {
+            Some(index) => self.asi.remove(index),
+            None => return Err(CharacterError::AsiNotTaken(improvement))
+        };
+        Ok(self.init_improvement())
+    }
+
+    /// Undo every spent ability score improvement
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Fighter);
+    /// player.add_xp(14000);
+    ///
+    /// player
+    ///     .asi_use(ed_5::AbilityImprovement::Single(AP::STR))
+    ///     .asi_use(ed_5::AbilityImprovement::Single(AP::CON))
+    ///     .asi_clear();
+    ///
+    /// assert!(player.get_all_asi().is_empty());
+    /// assert_eq!(player.get_asi_unused(), &2);
+    /// ```
+    pub fn asi_clear(&mut self) -> &mut Self {
+        self.asi = Vec::new();
+        self.init_improvement();
+        self
+    }
+
+    /// Double proficiency bonus of a proficient skill
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Elf::Wood)
+    ///     .ap_point_buy([8,14,14,15,10,8])
+    ///     .skill_use_expertise(Skill::Perception);
+    ///
+    /// assert_eq!(player.get_skill_bonus(Skill::Perception), 4);
+    /// ```
+    pub fn skill_use_expertise(&mut self, skill: Skill) -> &mut Self {
+        let _ = self.try_skill_use_expertise(skill);
+        self
+    }
+
+    /// Double proficiency bonus of a proficient skill,
+    /// returning the reason when expertise cannot be gained
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// assert_eq!(
+    ///     player.try_skill_use_expertise(Skill::Stealth).unwrap_err(),
+    ///     ed_5::CharacterError::SkillNotProficient(Skill::Stealth)
+    /// );
+    /// ```
+    pub fn try_skill_use_expertise(&mut self, skill: Skill)
+        -> Result<&mut Self, CharacterError> {
+        if !self.skill.contains(&skill) {
+            return Err(CharacterError::SkillNotProficient(skill));
+        }
+        if !self.expertise.insert(skill.clone()) {
+            return Err(CharacterError::ExpertiseKnown(skill));
+        }
+        Ok(self)
+    }
+
+    /// Remove expertise of a skill
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Elf::Wood)
+    ///     .ap_point_buy([8,14,14,15,10,8])
+    ///     .skill_use_expertise(Skill::Perception)
+    ///     .skill_remove_expertise(Skill::Perception);
+    ///
+    /// assert_eq!(player.get_skill_bonus(Skill::Perception), 2);
+    /// ```
+    pub fn skill_remove_expertise(&mut self, skill: Skill) -> &mut Self {
+        self.expertise.remove(&skill);
+        self
+    }
+
+    /// Use roll method to generate base stat
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.ap_dice_roll();
+    /// ```
+    pub fn ap_dice_roll(&mut self) -> &mut Self {
+        self.ap_unassigned = dice::roll();
+        // Reset ap_seq
+        self.ap_seq = Err(CharacterError::NoSequence);
+        self
+    }
+
+    /// Use Standard Array for base stat
+    /// Follow up of ap_assign_seq() method required 
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.ap_standard_array();
+    ///
+    /// assert_eq!(player.get_ap_unassigned(), &[15,14,13,12,10,8]);
+    /// ```
+    pub fn ap_standard_array(&mut self) -> &mut Self {
+        self.ap_unassigned = [15,14,13,12,10,8];
+        // Reset ap_seq
+        self.ap_seq = Err(CharacterError::NoSequence);
+        self
+    }
+
+    /// Use Heroic Array for base stat
+    /// Follow up of ap_assign_seq() method required 
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.ap_heroic_array();
+    ///
+    /// assert_eq!(player.get_ap_unassigned(), &[17,16,14,14,12,10]);
+    /// ```
+    pub fn ap_heroic_array(&mut self) -> &mut Self {
+        self.ap_unassigned = [17,16,14,14,12,10];
+        // Reset ap_seq
+        self.ap_seq = Err(CharacterError::NoSequence);
+        self
+    }
+
+    /// Use point buy method for base AP
+    /// 
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.ap_point_buy([8,13,14,15,12,10]);
+    ///
+    /// assert_eq!(player.get_all_ability_score(), [8,13,14,15,12,10]);
+    /// ```
+    pub fn ap_point_buy(&mut self, points: [usize; 6]) -> &mut Self {
+        let _ = self.try_ap_point_buy(points);
+        self
+    }
+
+    /// Use point buy method for base AP, returning the
+    /// reason when points do not follow point buy rules
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// assert_eq!(
+    ///     player.try_ap_point_buy([8,13,14,15,12,16]).unwrap_err(),
+    ///     ed_5::CharacterError::PointOutOfRange {
+    ///         points: [8,13,14,15,12,16], value: 16
+    ///     }
+    /// );
+    /// assert_eq!(player.get_all_ability_score(), [0,0,0,0,0,0]);
+    /// ```
+    pub fn try_ap_point_buy(&mut self, points: [usize; 6])
+        -> Result<&mut Self, CharacterError> {
+        self.ap_check_point_buy(points)?;
+        self.ap_unassigned = points;
+        self.ap_assign_seq([0,1,2,3,4,5]);
+        // Reset ap_seq
+        self.ap_seq = Err(CharacterError::NoSequence);
+        Ok(self)
+    }
+
+    /// Return the remainder from point buy if applicable
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// assert_eq!(
+    /// player.ap_check_point_buy([8,13,14,15,12,10]), Ok(0) 
+    /// );
+    ///
+    /// assert_eq!(
+    /// player.ap_check_point_buy([10,10,10,10,10,10]), Ok(15)
+    /// );
+    ///
+    /// assert_eq!(
+    /// player.ap_check_point_buy([15,15,15,15,8,8]),
+    /// Err(ed_5::CharacterError::PointBuyExceeded { limit: 27, assigned: 36 })
+    /// );
+    /// ```
+    /// -----------------------------------------------------
+    /// Refer to D&D rules for more information
+    /// regarding point buy.
+    pub fn ap_check_point_buy(&self, points: [usize; 6]) -> Result<usize, CharacterError> {
+        let mut sum: usize = 0;
+        for point in points {
+            // Check if point within rule's limit
+            if (8..=15).contains(&point) {
+                let x = point - 8;
+                let b = (x-(x%5))/5; // Match magic, try big brain it yourself
+                sum += x + (b * x%5);
+            }
+            else {
+                return Err(CharacterError::PointOutOfRange {
+                    points, value: point
+                });
+            }
+        }
+        if sum <= 27 {
+            Ok(27-sum)
+        }
+        else {
+            Err(CharacterError::PointBuyExceeded {
+                limit: 27, assigned: sum
+            })
+        }
+    }
+
+    /// Assign sequence to rolled stat
+    /// Rolled value i goes to ability `sequence[i]`
+    /// Important:
+    /// Value must start from 0, and ends at 5
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .ap_standard_array()
+    ///     .ap_assign_seq([2,1,3,5,4,0]);
+    ///
+    /// assert_eq!(player.get_all_ability_score(), [8,14,15,13,10,12]);
+    /// ```
+    /// ---------------------------------------
+    /// From the above example
+    /// If rolled stat is [2,4,6,8,10,12]
+    /// Rolled stat will be [12,4,2,6,10,8]
+    pub fn ap_assign_seq(&mut self, sequence: [usize; 6])
+        -> &mut Self {
+        let _ = self.try_ap_assign_seq(sequence);
+        self
+    }
+
+    /// Assign sequence to rolled stat, returning the
+    /// reason when the sequence is rejected and keeping
+    /// the previous sequence
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.ap_standard_array();
+    ///
+    /// assert_eq!(
+    ///     player.try_ap_assign_seq([0,1,2,3,4,4]).unwrap_err(),
+    ///     ed_5::CharacterError::DuplicateSequence {
+    ///         sequence: [0,1,2,3,4,4], index: 4
+    ///     }
+    /// );
+    /// ```
+    pub fn try_ap_assign_seq(&mut self, sequence: [usize; 6])
+        -> Result<&mut Self, CharacterError> {
+        self.ap_seq = Ok(Self::validate_seq(sequence)?);
+        Ok(self.init_base_ap())
+    }
+
+    /// Adjust an ability score from an item, curse or other
+    /// effect, adjustments to the same ability add up
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .ap_point_buy([10,10,10,10,10,10])
+    ///     .ap_adjust(AP::STR, 2)
+    ///     .ap_adjust(AP::WIS, -3);
+    ///
+    /// assert_eq!(player.get_all_ability_score(), [12,10,10,10,7,10]);
+    /// assert_eq!(player.get_ability_modifier(AP::WIS), -2);
+    /// ```
+    pub fn ap_adjust(&mut self, ability: AP, amount: isize) -> &mut Self {
+        let adjustment = &mut self.ap_adjustment[ability.get_index()];
+        *adjustment = adjustment.saturating_add(amount);
+        self
+    }
+
+    /// Remove all adjustments from items, curses or other effects
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .ap_point_buy([10,10,10,10,10,10])
+    ///     .ap_adjust(AP::STR, -4)
+    ///     .ap_clear_adjust();
+    ///
+    /// assert_eq!(player.get_ability_score(AP::STR), 10);
+    /// ```
+    pub fn ap_clear_adjust(&mut self) -> &mut Self {
+        self.ap_adjustment = [0,0,0,0,0,0];
+        self
+    }
+
+    /// Select/Change character class
+    /// Armor, weapon, tool and saving throw proficiencies
+    /// of previous class are replaced by the new class
+    /// Levels are kept, and merged with levels already
+    /// taken in the new class from multiclassing
+    ///
+    /// Example: 
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Cleric);
+    ///
+    /// assert_eq!(player.get_class(), &ed_5::Class::Cleric);
+    /// assert!(player.get_all_armor().contains(&Armor::Shield));
+    /// ```
+    pub fn class_select(&mut self, class: Class) -> &mut Self {
+        if class != self.class[0].class {
+            // Merge levels taken from multiclassing
+            let mut level = self.class[0].level;
+            if let Some(index) = self.class_index(&class) {
+                level += self.class.remove(index).level;
+            }
+            // Clean slate
+            self.class[0] = ClassLevel::new(class);
+            self.class[0].level = level;
+            // Unknown class can't be multiclassed
+            if class == Class::Unknown {
+                self.class.truncate(1);
+            }
+            // Initialisation
+            self.init_class();
+        }
+        self
+    }
+
+    /// Choose skill proficiency from class skill list
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .class_use_skill(Skill::Insight);
+    ///
+    /// assert!(player.get_all_skill().contains(&Skill::Insight));
+    /// assert_eq!(player.get_class_unused_skill(), &1);
+    /// ```
+    pub fn class_use_skill(&mut self, skill: Skill) -> &mut Self {
+        let _ = self.try_class_use_skill(skill);
+        self
+    }
+
+    /// Choose skill proficiency from class skill list,
+    /// returning the reason when the skill cannot be chosen
+    ///
+    /// A skill outside of class skill list can be chosen
+    /// for every class skill already granted by race
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Ranger);
+    ///
+    /// assert_eq!(
+    ///     player.try_class_use_skill(Skill::Arcana).unwrap_err(),
+    ///     ed_5::CharacterError::SkillNotAvailable(Skill::Arcana)
+    /// );
+    ///
+    /// // Elves are proficient in Perception, which is on
+    /// // the Ranger skill list, any other skill can be chosen
+    /// player.race_select(ed_5::Elf::Wood);
+    ///
+    /// assert!(player.try_class_use_skill(Skill::Arcana).is_ok());
+    /// ```
+    pub fn try_class_use_skill(&mut self, skill: Skill)
+        -> Result<&mut Self, CharacterError> {
+        self.try_class_index_use_skill(0, skill)
+    }
+
+    /// Choose skill proficiency granted when multiclassing
+    /// into a class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,14,13,10,15,10])
+    ///     .class_level_up(ed_5::Class::Ranger)
+    ///     .multiclass_use_skill(ed_5::Class::Ranger, Skill::Stealth);
+    ///
+    /// assert!(player.get_all_skill().contains(&Skill::Stealth));
+    /// ```
+    pub fn multiclass_use_skill(&mut self, class: Class, skill: Skill)
+        -> &mut Self {
+        let _ = self.try_multiclass_use_skill(class, skill);
+        self
+    }
+
+    /// Choose skill proficiency granted when multiclassing
+    /// into a class, returning the reason when the skill
+    /// cannot be chosen
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Ranger)
+    ///     .ap_point_buy([8,14,13,10,15,10])
+    ///     .class_level_up(ed_5::Class::Cleric);
+    ///
+    /// // Cleric grants no skill when multiclassing
+    /// assert_eq!(
+    ///     player.try_multiclass_use_skill(
+    ///         ed_5::Class::Cleric, Skill::Religion
+    ///     ).unwrap_err(),
+    ///     ed_5::CharacterError::NoClassSkillPoint
+    /// );
+    /// ```
+    pub fn try_multiclass_use_skill(&mut self, class: Class, skill: Skill)
+        -> Result<&mut Self, CharacterError> {
+        match self.class_index(&class) {
+            Some(index) => self.try_class_index_use_skill(index, skill),
+            None => Err(CharacterError::ClassNotTaken(class))
+        }
+    }
+
+    /// Remove skill proficiency chosen from class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .class_use_skill(Skill::Insight)
+    ///     .class_remove_skill(Skill::Insight);
+    ///
+    /// assert!(!player.get_all_skill().contains(&Skill::Insight));
+    /// assert_eq!(player.get_class_unused_skill(), &2);
+    /// ```
+    pub fn class_remove_skill(&mut self, skill: Skill) -> &mut Self {
+        let _ = self.try_class_remove_skill(skill);
+        self
+    }
+
+    /// Remove skill proficiency chosen from class, returning
+    /// an error when the skill was not chosen
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Cleric);
+    ///
+    /// assert_eq!(
+    ///     player.try_class_remove_skill(Skill::Insight).unwrap_err(),
+    ///     ed_5::CharacterError::SkillNotAssigned(Skill::Insight)
+    /// );
+    /// ```
+    pub fn try_class_remove_skill(&mut self, skill: Skill)
+        -> Result<&mut Self, CharacterError> {
+        let class = self.class[0].class;
+        self.try_multiclass_remove_skill(class, skill)
+    }
+
+    /// Remove skill proficiency chosen when multiclassing
+    /// into a class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,14,13,10,15,10])
+    ///     .class_level_up(ed_5::Class::Ranger)
+    ///     .multiclass_use_skill(ed_5::Class::Ranger, Skill::Stealth)
+    ///     .multiclass_remove_skill(ed_5::Class::Ranger, Skill::Stealth);
+    ///
+    /// assert!(!player.get_all_skill().contains(&Skill::Stealth));
+    /// ```
+    pub fn multiclass_remove_skill(&mut self, class: Class, skill: Skill)
+        -> &mut Self {
+        let _ = self.try_multiclass_remove_skill(class, skill);
+        self
+    }
+
+    /// Remove skill proficiency chosen when multiclassing
+    /// into a class, returning an error when the class was
+    /// not taken or the skill was not chosen
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Cleric);
+    ///
+    /// assert_eq!(
+    ///     player.try_multiclass_remove_skill(ed_5::Class::Ranger, Skill::Stealth)
+    ///         .unwrap_err(),
+    ///     ed_5::CharacterError::ClassNotTaken(ed_5::Class::Ranger)
+    /// );
+    /// ```
+    pub fn try_multiclass_remove_skill(&mut self, class: Class, skill: Skill)
+        -> Result<&mut Self, CharacterError> {
+        let index = self.class_index(&class)
+            .ok_or(CharacterError::ClassNotTaken(class))?;
+        if !self.class[index].used_skill.remove(&skill) {
+            return Err(CharacterError::SkillNotAssigned(skill));
+        }
+        Ok(self.init_class_skill().init_skill())
+    }
+
+    /// Remove all skill proficiencies chosen from class,
+    /// including those chosen from multiclassing
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .class_use_skill(Skill::Insight)
+    ///     .class_use_skill(Skill::History)
+    ///     .class_clear_skill();
+    ///
+    /// assert!(player.get_all_skill().is_empty());
+    /// ```
+    pub fn class_clear_skill(&mut self) -> &mut Self {
+        for entry in self.class.iter_mut() {
+            entry.used_skill = HashSet::new();
+        }
+        self.init_class_skill().init_skill()
+    }
+
+    /// Choose tool proficiency offered by class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Bard)
+    ///     .class_use_tool(Tools::Lute)
+    ///     .class_use_tool(Tools::Flute);
+    ///
+    /// assert!(player.get_all_tools().contains(&Tools::Lute));
+    /// assert_eq!(player.get_class_unused_tool(), &1);
+    /// ```
+    pub fn class_use_tool(&mut self, tool: Tools) -> &mut Self {
+        let _ = self.try_class_use_tool(tool);
+        self
+    }
+
+    /// Choose tool proficiency offered by class, returning
+    /// the reason when the tool cannot be chosen
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Monk);
+    ///
+    /// assert_eq!(
+    ///     player.try_class_use_tool(Tools::DiceSet).unwrap_err(),
+    ///     ed_5::CharacterError::ToolNotAvailable(Tools::DiceSet)
+    /// );
+    /// assert!(player.try_class_use_tool(Tools::CooksUtensils).is_ok());
+    /// assert_eq!(
+    ///     player.try_class_use_tool(Tools::Drum).unwrap_err(),
+    ///     ed_5::CharacterError::NoToolPoint
+    /// );
+    /// ```
+    pub fn try_class_use_tool(&mut self, tool: Tools)
+        -> Result<&mut Self, CharacterError> {
+        self.try_class_index_use_tool(0, tool)
+    }
+
+    /// Choose tool proficiency offered when multiclassing
+    /// into a class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,10,13,10,15,14])
+    ///     .class_level_up(ed_5::Class::Bard)
+    ///     .multiclass_use_tool(ed_5::Class::Bard, Tools::Viol);
+    ///
+    /// assert!(player.get_all_tools().contains(&Tools::Viol));
+    /// ```
+    pub fn multiclass_use_tool(&mut self, class: Class, tool: Tools)
+        -> &mut Self {
+        let _ = self.try_multiclass_use_tool(class, tool);
+        self
+    }
+
+    /// Choose tool proficiency offered when multiclassing
+    /// into a class, returning the reason when the tool
+    /// cannot be chosen
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Bard);
+    ///
+    /// assert_eq!(
+    ///     player.try_multiclass_use_tool(
+    ///         ed_5::Class::Monk, Tools::Drum
+    ///     ).unwrap_err(),
+    ///     ed_5::CharacterError::ClassNotTaken(ed_5::Class::Monk)
+    /// );
+    /// ```
+    pub fn try_multiclass_use_tool(&mut self, class: Class, tool: Tools)
+        -> Result<&mut Self, CharacterError> {
+        match self.class_index(&class) {
+            Some(index) => self.try_class_index_use_tool(index, tool),
+            None => Err(CharacterError::ClassNotTaken(class))
+        }
+    }
+
+    /// Remove tool proficiency chosen from class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Bard)
+    ///     .class_use_tool(Tools::Lute)
+    ///     .class_remove_tool(Tools::Lute);
+    ///
+    /// assert!(player.get_all_tools().is_empty());
+    /// assert_eq!(player.get_class_unused_tool(), &3);
+    /// ```
+    pub fn class_remove_tool(&mut self, tool: Tools) -> &mut Self {
+        let _ = self.try_class_remove_tool(tool);
+        self
+    }
+
+    /// Remove tool proficiency chosen from class, returning
+    /// an error when the tool was not chosen
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Bard);
+    ///
+    /// assert_eq!(
+    ///     player.try_class_remove_tool(Tools::Lute).unwrap_err(),
+    ///     ed_5::CharacterError::ToolNotAssigned(Tools::Lute)
+    /// );
+    /// ```
+    pub fn try_class_remove_tool(&mut self, tool: Tools)
+        -> Result<&mut Self, CharacterError> {
+        let class = self.class[0].class;
+        self.try_multiclass_remove_tool(class, tool)
+    }
+
+    /// Remove tool proficiency chosen when multiclassing
+    /// into a class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,10,13,10,15,14])
+    ///     .class_level_up(ed_5::Class::Bard)
+    ///     .multiclass_use_tool(ed_5::Class::Bard, Tools::Viol)
+    ///     .multiclass_remove_tool(ed_5::Class::Bard, Tools::Viol);
+    ///
+    /// assert!(!player.get_all_tools().contains(&Tools::Viol));
+    /// ```
+    pub fn multiclass_remove_tool(&mut self, class: Class, tool: Tools)
+        -> &mut Self {
+        let _ = self.try_multiclass_remove_tool(class, tool);
+        self
+    }
+
+    /// Remove tool proficiency chosen when multiclassing
+    /// into a class, returning an error when the class was
+    /// not taken or the tool was not chosen
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Cleric);
+    ///
+    /// assert_eq!(
+    ///     player.try_multiclass_remove_tool(ed_5::Class::Bard, Tools::Viol)
+    ///         .unwrap_err(),
+    ///     ed_5::CharacterError::ClassNotTaken(ed_5::Class::Bard)
+    /// );
+    /// ```
+    pub fn try_multiclass_remove_tool(&mut self, class: Class, tool: Tools)
+        -> Result<&mut Self, CharacterError> {
+        let index = self.class_index(&class)
+            .ok_or(CharacterError::ClassNotTaken(class))?;
+        if !self.class[index].used_tools.remove(&tool) {
+            return Err(CharacterError::ToolNotAssigned(tool));
+        }
+        Ok(self.init_tools())
+    }
+
+    /// Remove all tool proficiencies chosen from class,
+    /// including those chosen from multiclassing
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Bard)
+    ///     .class_use_tool(Tools::Lute)
+    ///     .class_use_tool(Tools::Horn)
+    ///     .class_clear_tool();
+    ///
+    /// assert!(player.get_all_tools().is_empty());
+    /// ```
+    pub fn class_clear_tool(&mut self) -> &mut Self {
+        for entry in self.class.iter_mut() {
+            entry.used_tools = HashSet::new();
+        }
+        self.init_tools()
+    }
+
+    /// Gain experience points, returning the number of
+    /// levels gained
+    /// Levels gained are added to the starting class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// assert_eq!(player.add_xp(200), 0);
+    /// assert_eq!(player.add_xp(800), 2);
+    /// assert_eq!(player.get_level(), 3);
+    /// ```
+    pub fn add_xp(&mut self, xp: usize) -> usize {
+        let level = self.get_level();
+        self.xp = self.xp.saturating_add(xp);
+        while self.get_level() < 20
+            && self.xp >= XP_THRESHOLD[self.get_level()] {
+            self.class[0].level += 1;
+        }
+        self.init_hit_point().init_improvement();
+        self.get_level() - level
+    }
+
+    /// Gain a level in starting class without experience
+    /// points (milestone)
+    /// Experience points are raised to the new level's
+    /// threshold
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .level_up()
+    ///     .level_up();
+    ///
+    /// assert_eq!(player.get_level(), 3);
+    /// assert_eq!(player.get_xp(), &900);
+    /// ```
+    pub fn level_up(&mut self) -> &mut Self {
+        let _ = self.try_level_up();
+        self
+    }
+
+    /// Gain a level in starting class without experience
+    /// points, returning an error at maximum level
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.add_xp(355000);
+    ///
+    /// assert_eq!(
+    ///     player.try_level_up().unwrap_err(),
+    ///     ed_5::CharacterError::MaxLevel
+    /// );
+    /// ```
+    pub fn try_level_up(&mut self) -> Result<&mut Self, CharacterError> {
+        let class = self.class[0].class;
+        self.try_class_level_up(class)
+    }
+
+    /// Gain a level in a class, multiclassing into the
+    /// class when it has not been taken yet
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,14,13,10,15,10])
+    ///     .class_level_up(ed_5::Class::Ranger)
+    ///     .class_level_up(ed_5::Class::Ranger);
+    ///
+    /// assert_eq!(player.get_all_class(), vec![
+    ///     (ed_5::Class::Cleric, 1),
+    ///     (ed_5::Class::Ranger, 2)
+    /// ]);
+    /// assert_eq!(player.get_level(), 3);
+    /// ```
+    pub fn class_level_up(&mut self, class: Class) -> &mut Self {
+        let _ = self.try_class_level_up(class);
+        self
+    }
+
+    /// Gain a level in a class, returning the reason when
+    /// the level cannot be gained
+    ///
+    /// Multiclassing requires ability scores of both the
+    /// current classes and the new class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,12,13,10,15,10]);
+    ///
+    /// assert_eq!(
+    ///     player.try_class_level_up(ed_5::Class::Ranger).unwrap_err(),
+    ///     ed_5::CharacterError::MulticlassRequirement {
+    ///         class: ed_5::Class::Ranger, ability: AP::DEX, required: 13
+    ///     }
+    /// );
+    /// ```
+    pub fn try_class_level_up(&mut self, class: Class)
+        -> Result<&mut Self, CharacterError> {
+        if self.get_level() >= 20 {
+            return Err(CharacterError::MaxLevel);
+        }
+        match self.class_index(&class) {
+            Some(index) => self.class[index].level += 1,
+            None => {
+                if class == Class::Unknown
+                    || self.class[0].class == Class::Unknown {
+                    return Err(CharacterError::MulticlassUnknown);
+                }
+                for entry in &self.class {
+                    self.check_multiclass_req(entry.class)?;
+                }
+                self.check_multiclass_req(class)?;
+                self.class.push(ClassLevel::new(class));
+            }
+        }
+        self.xp = self.xp.max(XP_THRESHOLD[self.get_level() - 1]);
+        Ok(self.init_class())
+    }
+
+    /// Select method used to gain hit points on level up
+    /// Hit points of first level are always the maximum
+    /// of hit die
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,10,14,15,12,10])
+    ///     .hp_method_select(ed_5::HitPointMethod::Roll)
+    ///     .level_up();
+    ///
+    /// assert!((13..=20).contains(&player.get_max_hp()));
+    /// ```
+    pub fn hp_method_select(&mut self, method: HitPointMethod) -> &mut Self {
+        self.hp_method = method;
+        self
+    }
+
+    /// Add items to inventory
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .inventory_add(ed_5::Item::Weapon(Weapon::Dagger), 1)
+    ///     .inventory_add(ed_5::Item::Weapon(Weapon::Dagger), 2);
+    ///
+    /// assert_eq!(player.get_inventory(), &[(ed_5::Item::Weapon(Weapon::Dagger), 3)]);
+    /// ```
+    pub fn inventory_add(&mut self, item: Item, quantity: usize) -> &mut Self {
+        if quantity == 0 {
+            return self;
+        }
+        match self.inventory.iter_mut().find(|(carried, _)| carried == &item) {
+            Some((_, count)) => *count += quantity,
+            None => self.inventory.push((item, quantity))
+        }
+        self
+    }
+
+    /// Remove items from inventory
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .inventory_add(ed_5::Item::Gear(ed_5::Gear::Torch), 5)
+    ///     .inventory_remove(ed_5::Item::Gear(ed_5::Gear::Torch), 2);
+    ///
+    /// assert_eq!(player.get_inventory(), &[(ed_5::Item::Gear(ed_5::Gear::Torch), 3)]);
+    /// ```
+    pub fn inventory_remove(&mut self, item: Item, quantity: usize) -> &mut Self {
+        let _ = self.try_inventory_remove(item, quantity);
+        self
+    }
+
+    /// Remove items from inventory, returning an error when
+    /// not enough of the item is carried
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.inventory_add(ed_5::Item::Gear(ed_5::Gear::Torch), 1);
+    ///
+    /// assert_eq!(
+    ///     player.try_inventory_remove(ed_5::Item::Gear(ed_5::Gear::Torch), 2).unwrap_err(),
+    ///     ed_5::CharacterError::NotEnoughItem {
+    ///         item: ed_5::Item::Gear(ed_5::Gear::Torch), carried: 1
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     player.try_inventory_remove(ed_5::Item::Gear(ed_5::Gear::Candle), 1).unwrap_err(),
+    ///     ed_5::CharacterError::ItemNotCarried(ed_5::Item::Gear(ed_5::Gear::Candle))
+    /// );
+    /// assert!(player.try_inventory_remove(ed_5::Item::Gear(ed_5::Gear::Torch), 1).is_ok());
+    /// assert!(player.get_inventory().is_empty());
+    /// ```
+    pub fn try_inventory_remove(&mut self, item: Item, quantity: usize)
+        -> Result<&mut Self, CharacterError> {
+        let index = match self.inventory.iter().position(|(carried, _)| carried == &item) {
+            Some(index) => index,
+            None => return Err(CharacterError::ItemNotCarried(item))
+        };
+        let carried = self.inventory[index].1;
+        if quantity > carried {
+            return Err(CharacterError::NotEnoughItem { item, carried });
+        }
+        if quantity == carried {
+            self.inventory.remove(index);
+            // Take off armor no longer carried
+            if let Item::Armor(armor) = item {
+                self.armor_unequip(armor);
+            }
+        }
+        else {
+            self.inventory[index].1 -= quantity;
+        }
+        Ok(self)
+    }
+
+    /// Remove every item from inventory, taking off
+    /// equipped armor and shield
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .inventory_add(ed_5::Item::Gear(ed_5::Gear::Backpack), 1)
+    ///     .inventory_clear();
+    ///
+    /// assert!(player.get_inventory().is_empty());
+    /// ```
+    pub fn inventory_clear(&mut self) -> &mut Self {
+        self.inventory = Vec::new();
+        self.equipped_armor = None;
+        self.equipped_shield = false;
+        self
+    }
+
+    /// Select rule used to check encumbrance
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Basic)
+    ///     .ap_point_buy([8,10,10,10,10,10])
+    ///     .inventory_add(ed_5::Item::Armor(ed_5::ArmorItem::Plate), 1);
+    ///
+    /// assert_eq!(player.get_encumbrance(), ed_5::Encumbrance::Unencumbered);
+    ///
+    /// player.encumbrance_rule_select(ed_5::EncumbranceRule::Variant);
+    ///
+    /// assert_eq!(player.get_encumbrance(), ed_5::Encumbrance::Encumbered);
+    /// ```
+    pub fn encumbrance_rule_select(&mut self, rule: EncumbranceRule) -> &mut Self {
+        self.encumbrance_rule = rule;
+        self
+    }
+
+    /// Equip carried armor or shield, replacing armor
+    /// already worn
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .inventory_add(ed_5::Item::Armor(ed_5::ArmorItem::Leather), 1)
+    ///     .armor_equip(ed_5::ArmorItem::Leather);
+    ///
+    /// assert_eq!(player.get_equipped_armor(), Some(ed_5::ArmorItem::Leather));
+    /// ```
+    pub fn armor_equip(&mut self, armor: ArmorItem) -> &mut Self {
+        let _ = self.try_armor_equip(armor);
+        self
+    }
+
+    /// Equip carried armor or shield, returning an error
+    /// when it is not in inventory
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// assert_eq!(
+    ///     player.try_armor_equip(ed_5::ArmorItem::Shield).unwrap_err(),
+    ///     ed_5::CharacterError::ItemNotCarried(ed_5::Item::Armor(ed_5::ArmorItem::Shield))
+    /// );
+    /// ```
+    pub fn try_armor_equip(&mut self, armor: ArmorItem)
+        -> Result<&mut Self, CharacterError> {
+        let item = Item::Armor(armor);
+        if !self.inventory.iter().any(|(carried, _)| carried == &item) {
+            return Err(CharacterError::ItemNotCarried(item));
+        }
+        if armor.get_category() == Armor::Shield {
+            self.equipped_shield = true;
+        }
+        else {
+            self.equipped_armor = Some(armor);
+        }
+        Ok(self)
+    }
+
+    /// Take off armor or shield when equipped
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .inventory_add(ed_5::Item::Armor(ed_5::ArmorItem::Shield), 1)
+    ///     .armor_equip(ed_5::ArmorItem::Shield)
+    ///     .armor_unequip(ed_5::ArmorItem::Shield);
+    ///
+    /// assert!(!player.get_equipped_shield());
+    /// ```
+    pub fn armor_unequip(&mut self, armor: ArmorItem) -> &mut Self {
+        if armor.get_category() == Armor::Shield {
+            self.equipped_shield = false;
+        }
+        else if self.equipped_armor == Some(armor) {
+            self.equipped_armor = None;
+        }
+        self
+    }
+
+    /// Take damage, temporary hit points are lost first
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,10,14,15,12,10])
+    ///     .hp_gain_temp(3)
+    ///     .hp_take_damage(5);
+    ///
+    /// assert_eq!(player.get_temp_hp(), &0);
+    /// assert_eq!(player.get_current_hp(), 8);
+    /// ```
+    pub fn hp_take_damage(&mut self, damage: usize) -> &mut Self {
+        let absorbed = damage.min(self.hp_temp);
+        self.hp_temp -= absorbed;
+        self.hp_damage = self.hp_damage.saturating_add(damage - absorbed)
+            .min(self.get_max_hp());
+        self
+    }
+
+    /// Regain hit points, up to hit point maximum
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,10,14,15,12,10])
+    ///     .hp_take_damage(5)
+    ///     .hp_heal(10);
+    ///
+    /// assert_eq!(player.get_current_hp(), 10);
+    /// ```
+    pub fn hp_heal(&mut self, hp: usize) -> &mut Self {
+        self.hp_damage = self.hp_damage.saturating_sub(hp);
+        self
+    }
+
+    /// Gain temporary hit points, which do not stack
+    /// with existing temporary hit points
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .hp_gain_temp(5)
+    ///     .hp_gain_temp(3);
+    ///
+    /// assert_eq!(player.get_temp_hp(), &5);
+    /// ```
+    pub fn hp_gain_temp(&mut self, hp: usize) -> &mut Self {
+        self.hp_temp = self.hp_temp.max(hp);
+        self
+    }
+
+    /// Return race name as String
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// assert_eq!(player.get_race(), "Unknown(Unknown)".to_string());
+    /// ```
+    pub fn get_race(&self) -> String {
+        self.race.as_string()
+    }
+
+    /// Return reference to character background
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.background_select(ed_5::Background::Sage);
+    ///
+    /// assert_eq!(player.get_background(), &ed_5::Background::Sage);
+    /// assert_eq!(player.get_background().as_string(), "Sage".to_string());
+    /// ```
+    pub fn get_background(&self) -> &Background {
+        &self.background
+    }
+
+    /// Return reference to feature granted by background
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.background_select(ed_5::Background::Soldier);
+    ///
+    /// assert_eq!(player.get_background_feature().name, "Military Rank");
+    /// ```
+    pub fn get_background_feature(&self) -> &Feature {
+        &self.buffer_background.feature
+    }
+
+    /// Return starting equipment granted by background
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.background_select(ed_5::Background::Criminal);
+    ///
+    /// assert!(player.get_background_equipment()
+    ///     .contains(&(ed_5::Item::Gear(ed_5::Gear::Crowbar), 1)));
+    /// ```
+    pub fn get_background_equipment(&self) -> &[(Item, usize)] {
+        &self.buffer_background.equipment
+    }
+
+    /// Return refernce to value of assignable ability
+    /// score gained from certain race
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Human::Variant);
+    ///
+    /// assert_eq!(player.get_race_unused_ap(), &2);
+    ///
+    /// player.race_use_ap(AP::STR);
+    ///
+    /// assert_eq!(player.get_race_unused_ap(), &1);
+    /// ```
+    pub fn get_race_unused_ap(&self) -> &usize {
+        &self.race_usable_ap
+    }
+
+    /// Return reference to number of skills left to
+    /// choose from race
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::HalfElf::Basic);
+    ///
+    /// assert_eq!(player.get_race_unused_skill(), &2);
+    /// ```
+    pub fn get_race_unused_skill(&self) -> &usize {
+        &self.race_skill_point
+    }
+
+    /// Return reference to number of tools left to
+    /// choose from race
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Dwarf::Duegar);
+    ///
+    /// assert_eq!(player.get_race_unused_tool(), &1);
+    /// ```
+    pub fn get_race_unused_tool(&self) -> &usize {
+        &self.race_tool_point
+    }
+
+    /// Return reference to number of weapons left to
+    /// choose from race
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Elf::Wood);
+    ///
+    /// assert_eq!(player.get_race_unused_weap(), &0);
+    /// ```
+    pub fn get_race_unused_weap(&self) -> &usize {
+        &self.race_weap_point
+    }
+
+    /// Return reference to number of racial traits left
+    /// to pick from race options
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Dragonborn::Basic);
+    ///
+    /// assert_eq!(player.get_race_unused_trait(), &1);
+    /// ```
+    pub fn get_race_unused_trait(&self) -> &usize {
+        &self.race_trait_point
+    }
+
+    /// Return every choice offered by race
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Human::Variant);
+    ///
+    /// assert_eq!(player.get_race_choice(), &[
+    ///     ed_5::RaceChoice::Ability(ed_5::AbilityGrant::new(1, 2)),
+    ///     ed_5::RaceChoice::Language,
+    ///     ed_5::RaceChoice::Skill(Skill::get_all())
+    /// ]);
+    /// ```
+    pub fn get_race_choice(&self) -> &[RaceChoice] {
+        match &self.buffer_race {
+            Some(buff_ptr) => &buff_ptr.choice,
+            None => &[]
+        }
+    }
+
+    /// Return reference to number of skills left to
+    /// choose from class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Ranger);
+    ///
+    /// assert_eq!(player.get_class_unused_skill(), &3);
+    /// ```
+    pub fn get_class_unused_skill(&self) -> &usize {
+        &self.class[0].skill_point
+    }
+
+    /// Return reference to number of tools left to
+    /// choose from class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Bard);
+    ///
+    /// assert_eq!(player.get_class_unused_tool(), &3);
+    /// ```
+    pub fn get_class_unused_tool(&self) -> &usize {
+        &self.class[0].tool_point
+    }
+
+    /// Return reference to number of languages left to
+    /// choose from background
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.background_select(ed_5::Background::Acolyte);
+    ///
+    /// assert_eq!(player.get_background_unused_lang(), &2);
+    /// ```
+    pub fn get_background_unused_lang(&self) -> &usize {
+        &self.background_lang_point
+    }
+
+    /// Return reference to number of tools left to
+    /// choose from background
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.background_select(ed_5::Background::Entertainer);
+    ///
+    /// assert_eq!(player.get_background_unused_tool(), &1);
+    /// ```
+    pub fn get_background_unused_tool(&self) -> &usize {
+        &self.background_tool_point
+    }
+
+    /// Return reference to number of feat slots left,
+    /// from race and from ability score improvement levels
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Variant)
+    ///     .class_select(ed_5::Class::Fighter)
+    ///     .add_xp(14000);
+    ///
+    /// // Variant Human + Fighter levels 4 and 6
+    /// assert_eq!(player.get_feat_unused(), &3);
+    /// ```
+    pub fn get_feat_unused(&self) -> &usize {
+        &self.feat_point
+    }
+
+    /// Return feats taken, in the order they were taken
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Variant)
+    ///     .feat_use(ed_5::Feat::Tough);
+    ///
+    /// assert_eq!(player.get_all_feat(), &[ed_5::Feat::Tough]);
+    /// ```
+    pub fn get_all_feat(&self) -> &[Feat] {
+        &self.feat
+    }
+
+    /// Return reference to number of ability score
+    /// improvements left to spend
+    ///
+    /// Feats taken outside of race feat slots use up
+    /// ability score improvements
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Rogue)
+    ///     .add_xp(64000);
+    ///
+    /// assert_eq!(player.get_asi_unused(), &3);
+    ///
+    /// player.feat_use(ed_5::Feat::Alert);
+    ///
+    /// assert_eq!(player.get_asi_unused(), &2);
+    /// ```
+    pub fn get_asi_unused(&self) -> &usize {
+        &self.asi_point
+    }
+
+    /// Return spent ability score improvements, in the
+    /// order they were spent
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Fighter);
+    /// player.add_xp(2700);
+    /// player.asi_use(ed_5::AbilityImprovement::Split(AP::STR, AP::CON));
+    ///
+    /// assert_eq!(player.get_all_asi(), &[
+    ///     ed_5::AbilityImprovement::Split(AP::STR, AP::CON)
+    /// ]);
+    /// ```
+    pub fn get_all_asi(&self) -> &[AbilityImprovement] {
+        &self.asi
+    }
+
+    /// Return number of skills left to choose from
+    /// multiclassing into a class, None when the class
+    /// has not been taken
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,14,13,10,15,10])
+    ///     .class_level_up(ed_5::Class::Ranger);
+    ///
+    /// assert_eq!(player.get_multiclass_unused_skill(ed_5::Class::Ranger), Some(1));
+    /// assert_eq!(player.get_multiclass_unused_skill(ed_5::Class::Unknown), None);
+    /// ```
+    pub fn get_multiclass_unused_skill(&self, class: Class) -> Option<usize> {
+        self.class_index(&class).map(|index| self.class[index].skill_point)
+    }
+
+    /// Return number of tools left to choose from
+    /// multiclassing into a class, None when the class
+    /// has not been taken
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,10,13,10,15,14])
+    ///     .class_level_up(ed_5::Class::Bard);
+    ///
+    /// assert_eq!(player.get_multiclass_unused_tool(ed_5::Class::Bard), Some(1));
+    /// assert_eq!(player.get_multiclass_unused_tool(ed_5::Class::Monk), None);
+    /// ```
+    pub fn get_multiclass_unused_tool(&self, class: Class) -> Option<usize> {
+        self.class_index(&class).map(|index| self.class[index].tool_point)
+    }
+
+    /// Return value of specific ability score from
+    /// the final/total caculated ability scores
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Variant)
+    ///     .race_use_ap(AP::INT);
+    ///
+    /// let val = player.get_ability_score(AP::INT);
+    ///
+    /// assert_eq!(val, 1);
+    /// ```
+    pub fn get_ability_score(&self, ap: AP) -> usize {
+        let scores = self.get_all_ability_score();
+        scores[ap.get_index()]
+    }
+
+    /// Calculate and return the total ability scores,
+    /// kept between 1 and 30 once base scores are assigned
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Variant)
+    ///     .race_use_ap(AP::INT);
+    ///
+    /// let val = player.get_all_ability_score();
+    ///
+    /// assert_eq!(val, [0,0,0,1,0,0]);
+    /// ```
+    pub fn get_all_ability_score(&self) -> [usize; 6] {
+        let mut ability_scores = self.unadjusted_ability_score();
+        self.calculate_adjustment(&mut ability_scores);
+        // Scores can't drop below 1 once rolled or bought
+        let min = if self.base_ap == [0,0,0,0,0,0] { 0 } else { 1 };
+        ability_scores.map(|score| score.clamp(min, 30) as usize)
+    }
+
+    /// Return reference to character level
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let player = ed_5::Character::build();
+    ///
+    /// assert_eq!(player.get_level(), 1);
+    /// ```
+    pub fn get_level(&self) -> usize {
+        self.class.iter().map(|entry| entry.level).sum()
+    }
+
+    /// Return reference to experience points
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.add_xp(450);
+    ///
+    /// assert_eq!(player.get_xp(), &450);
+    /// ```
+    pub fn get_xp(&self) -> &usize {
+        &self.xp
+    }
+
+    /// Return experience points required for next level,
+    /// None at maximum level
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// assert_eq!(player.get_xp_next_level(), Some(300));
+    ///
+    /// player.add_xp(355000);
+    ///
+    /// assert_eq!(player.get_xp_next_level(), None);
+    /// ```
+    pub fn get_xp_next_level(&self) -> Option<usize> {
+        XP_THRESHOLD.get(self.get_level()).copied()
+    }
+
+    /// Return hit point maximum, recalculated from hit die
+    /// of every level and current CON modifier
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Ranger)
+    ///     .ap_point_buy([8,15,14,10,13,10])
+    ///     .level_up();
+    ///
+    /// // Level 1: 10 + 2, Level 2: 6 + 2
+    /// assert_eq!(player.get_max_hp(), 20);
+    ///
+    /// player.race_select(ed_5::Elf::Sea);
+    ///
+    /// assert_eq!(player.get_max_hp(), 20);
+    ///
+    /// player.race_select(ed_5::Dwarf::Duegar);
+    ///
+    /// assert_eq!(player.get_max_hp(), 22);
+    /// ```
+    pub fn get_max_hp(&self) -> usize {
+        let modifier = self.get_ability_modifier(AP::CON);
+        let bonus: usize = self.trait_effect()
+            .map(|effect| match effect {
+                TraitEffect::HitPointPerLevel(hp) => hp,
+                _ => 0
+            })
+            .chain(self.feat_effect().map(|effect| match effect {
+                FeatEffect::HitPointPerLevel(hp) => hp,
+                _ => 0
+            }))
+            .sum();
+        self.class.iter()
+            .flat_map(|entry| entry.hp_roll.iter())
+            .map(|roll| (*roll as isize + modifier).max(1) as usize + bonus)
+            .sum()
+    }
+
+    /// Return current hit points
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,10,14,15,12,10])
+    ///     .hp_take_damage(4);
+    ///
+    /// assert_eq!(player.get_current_hp(), 6);
+    /// ```
+    pub fn get_current_hp(&self) -> usize {
+        self.get_max_hp().saturating_sub(self.hp_damage)
+    }
+
+    /// Return reference to temporary hit points
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let player = ed_5::Character::build();
+    ///
+    /// assert_eq!(player.get_temp_hp(), &0);
+    /// ```
+    pub fn get_temp_hp(&self) -> &usize {
+        &self.hp_temp
+    }
+
+    /// Return modifier of specific ability score
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.ap_point_buy([8,13,14,15,12,10]);
+    ///
+    /// assert_eq!(player.get_ability_modifier(AP::STR), -1);
+    /// assert_eq!(player.get_ability_modifier(AP::INT), 2);
+    /// ```
+    pub fn get_ability_modifier(&self, ap: AP) -> isize {
+        let modifiers = self.get_all_ability_modifier();
+        modifiers[ap.get_index()]
+    }
+
+    /// Calculate and return modifiers of all ability scores
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Elf::High)
+    ///     .ap_point_buy([8,13,14,15,12,10]);
+    ///
+    /// assert_eq!(player.get_all_ability_modifier(), [-1,2,2,3,1,0]);
+    /// ```
+    pub fn get_all_ability_modifier(&self) -> [isize; 6] {
+        self.get_all_ability_score().map(Self::ability_modifier)
+    }
+
+    /// Return proficiency bonus of current level
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// assert_eq!(player.get_proficiency_bonus(), 2);
+    ///
+    /// player.add_xp(6500);
+    ///
+    /// assert_eq!(player.get_proficiency_bonus(), 3);
+    /// ```
+    pub fn get_proficiency_bonus(&self) -> isize {
+        Self::proficiency_bonus(self.get_level())
+    }
+
+    /// Return initiative bonus
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Elf::Wood)
+    ///     .ap_point_buy([8,14,14,15,10,8]);
+    ///
+    /// assert_eq!(player.get_initiative(), 3);
+    /// ```
+    pub fn get_initiative(&self) -> isize {
+        let bonus: isize = self.feat_effect()
+            .map(|effect| match effect {
+                FeatEffect::Initiative(bonus) => bonus,
+                _ => 0
+            })
+            .sum();
+        self.get_ability_modifier(AP::DEX) + bonus
+    }
+
+    /// Return passive Wisdom (Perception)
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Elf::Wood)
+    ///     .ap_point_buy([8,14,14,15,10,8]);
+    ///
+    /// // 10 + WIS modifier (0) + proficiency bonus (2)
+    /// assert_eq!(player.get_passive_perception(), 12);
+    /// ```
+    pub fn get_passive_perception(&self) -> isize {
+        self.passive_skill(Skill::Perception)
+    }
+
+    /// Return passive Intelligence (Investigation)
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.ap_point_buy([8,14,14,15,10,8]);
+    ///
+    /// assert_eq!(player.get_passive_investigation(), 12);
+    /// ```
+    pub fn get_passive_investigation(&self) -> isize {
+        self.passive_skill(Skill::Investigation)
+    }
+
+    /// Return passive Wisdom (Insight)
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.ap_point_buy([8,14,14,15,8,10]);
+    ///
+    /// assert_eq!(player.get_passive_insight(), 9);
+    /// ```
+    pub fn get_passive_insight(&self) -> isize {
+        self.passive_skill(Skill::Insight)
+    }
+
+    /// Return carrying capacity in pounds, STR score
+    /// multiplied by 15 and scaled by size, one size
+    /// larger with Powerful Build
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Basic)
+    ///     .ap_point_buy([15,14,13,12,10,8]);
+    ///
+    /// assert_eq!(player.get_carrying_capacity(), 240);
+    /// ```
+    pub fn get_carrying_capacity(&self) -> usize {
+        let capacity = self.get_ability_score(AP::STR) * 15;
+        let larger = self.trait_effect()
+            .any(|effect| effect == TraitEffect::CarryingSize);
+        match (&self.size, larger) {
+            (Size::Tiny, false) => capacity / 2,
+            (Size::Medium, true) | (Size::Large, false) => capacity * 2,
+            (Size::Large, true) => capacity * 4,
+            _ => capacity
+        }
+    }
+
+    /// Return weight in pounds a character can push,
+    /// drag or lift, twice the carrying capacity
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Basic)
+    ///     .ap_point_buy([15,14,13,12,10,8]);
+    ///
+    /// assert_eq!(player.get_push_drag_lift(), 480);
+    /// ```
+    pub fn get_push_drag_lift(&self) -> usize {
+        self.get_carrying_capacity() * 2
+    }
+
+    /// Return items carried with their quantities
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let player = ed_5::Character::build();
+    ///
+    /// assert!(player.get_inventory().is_empty());
+    /// ```
+    pub fn get_inventory(&self) -> &[(Item, usize)] {
+        &self.inventory
+    }
+
+    /// Return total weight carried in pounds
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .inventory_add(ed_5::Item::Weapon(Weapon::Longsword), 1)
+    ///     .inventory_add(ed_5::Item::Gear(ed_5::Gear::Rations), 5)
+    ///     .inventory_add(ed_5::Item::Tool(Tools::TheivesTools), 1);
+    ///
+    /// assert_eq!(player.get_carried_weight(), 14.0);
+    /// ```
+    pub fn get_carried_weight(&self) -> f32 {
+        self.inventory.iter()
+            .map(|(item, quantity)| item.get_weight() * *quantity as f32)
+            .sum()
+    }
+
+    /// Return encumbrance level of carried weight under
+    /// selected encumbrance rule
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Halfling::Lightfoot)
+    ///     .ap_point_buy([8,10,10,10,10,10])
+    ///     .inventory_add(ed_5::Item::Gear(ed_5::Gear::Chest), 5);
+    ///
+    /// assert_eq!(player.get_encumbrance(), ed_5::Encumbrance::OverCapacity);
+    ///
+    /// player.inventory_add(ed_5::Item::Gear(ed_5::Gear::Chest), 5);
+    ///
+    /// assert_eq!(player.get_encumbrance(), ed_5::Encumbrance::Immobile);
+    /// ```
+    pub fn get_encumbrance(&self) -> Encumbrance {
+        let weight = self.get_carried_weight();
+        let capacity = self.get_carrying_capacity() as f32;
+        if weight > self.get_push_drag_lift() as f32 {
+            Encumbrance::Immobile
+        }
+        else if weight > capacity {
+            Encumbrance::OverCapacity
+        }
+        else if self.encumbrance_rule == EncumbranceRule::Standard {
+            Encumbrance::Unencumbered
+        }
+        else if weight > capacity * 2.0 / 3.0 {
+            Encumbrance::HeavilyEncumbered
+        }
+        else if weight > capacity / 3.0 {
+            Encumbrance::Encumbered
+        }
+        else {
+            Encumbrance::Unencumbered
+        }
+    }
+
+    /// Return walking speed in feet after encumbrance and
+    /// armor STR requirement
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Human::Basic)
+    ///     .ap_point_buy([8,10,10,10,10,10])
+    ///     .encumbrance_rule_select(ed_5::EncumbranceRule::Variant);
+    ///
+    /// assert_eq!(player.get_speed(), 30);
+    ///
+    /// player.inventory_add(ed_5::Item::Armor(ed_5::ArmorItem::ChainMail), 1);
+    ///
+    /// assert_eq!(player.get_speed(), 20);
+    /// ```
+    pub fn get_speed(&self) -> usize {
+        let mut speed = self.speed;
+        if self.get_armor_penalty().contains(&ArmorPenalty::Strength)
+            && !self.trait_effect().any(|effect| effect == TraitEffect::HeavyArmorSpeed) {
+            speed = speed.saturating_sub(10);
+        }
+        match self.get_encumbrance() {
+            Encumbrance::Unencumbered => speed,
+            Encumbrance::Encumbered => speed.saturating_sub(10),
+            Encumbrance::HeavilyEncumbered => speed.saturating_sub(20),
+            Encumbrance::OverCapacity => speed.min(5),
+            Encumbrance::Immobile => 0
+        }
+    }
+
+    /// Return armor worn, None when unarmored
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let player = ed_5::Character::build();
+    ///
+    /// assert_eq!(player.get_equipped_armor(), None);
+    /// ```
+    pub fn get_equipped_armor(&self) -> Option<ArmorItem> {
+        self.equipped_armor
+    }
+
+    /// Return whether a shield is equipped
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let player = ed_5::Character::build();
+    ///
+    /// assert!(!player.get_equipped_shield());
+    /// ```
+    pub fn get_equipped_shield(&self) -> bool {
+        self.equipped_shield
+    }
+
+    /// Return armor class from equipped armor, shield and
+    /// DEX modifier, or from unarmored defense
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.ap_point_buy([14,14,14,10,12,8]);
+    ///
+    /// // 10 + DEX modifier (2)
+    /// assert_eq!(player.get_armor_class(), 12);
+    ///
+    /// player
+    ///     .inventory_add(ed_5::Item::Armor(ed_5::ArmorItem::ChainShirt), 1)
+    ///     .inventory_add(ed_5::Item::Armor(ed_5::ArmorItem::Shield), 1)
+    ///     .armor_equip(ed_5::ArmorItem::ChainShirt)
+    ///     .armor_equip(ed_5::ArmorItem::Shield);
+    ///
+    /// // 13 + DEX modifier (max 2) + shield (2)
+    /// assert_eq!(player.get_armor_class(), 17);
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Barbarian)
+    ///     .armor_unequip(ed_5::ArmorItem::ChainShirt);
+    ///
+    /// // 10 + DEX modifier (2) + CON modifier (2) + shield (2)
+    /// assert_eq!(player.get_armor_class(), 16);
+    /// ```
+    pub fn get_armor_class(&self) -> usize {
+        let dex = self.get_ability_modifier(AP::DEX);
+        let shield = match self.equipped_shield {
+            true => ArmorItem::Shield.get_ac() as isize,
+            false => 0
+        };
+        let armor_class = match self.equipped_armor {
+            Some(armor) => {
+                let dex = match armor.get_dex_bonus() {
+                    DexBonus::Full => dex,
+                    DexBonus::Max(max) => dex.min(max),
+                    DexBonus::Ignored => 0
+                };
+                armor.get_ac() as isize + dex + shield
+            }
+            None => {
+                let classes: Vec<Class> = self.class.iter()
+                    .map(|entry| entry.class)
+                    .collect();
+                let mut armor_class = 10 + dex + shield;
+                // Unarmored defense
+                if classes.contains(&Class::Barbarian) {
+                    armor_class = armor_class.max(
+                        10 + dex + self.get_ability_modifier(AP::CON) + shield
+                    );
+                }
+                if classes.contains(&Class::Monk) && !self.equipped_shield {
+                    armor_class = armor_class.max(
+                        10 + dex + self.get_ability_modifier(AP::WIS)
+                    );
+                }
+                armor_class
+            }
+        };
+        armor_class.max(0) as usize
+    }
+
+    /// Return drawbacks of equipped armor and shield
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .ap_point_buy([14,14,14,10,12,8])
+    ///     .inventory_add(ed_5::Item::Armor(ed_5::ArmorItem::Plate), 1)
+    ///     .armor_equip(ed_5::ArmorItem::Plate);
+    ///
+    /// assert_eq!(player.get_armor_penalty(), HashSet::from([
+    ///     ed_5::ArmorPenalty::NotProficient,
+    ///     ed_5::ArmorPenalty::Strength,
+    ///     ed_5::ArmorPenalty::StealthDisadvantage
+    /// ]));
+    ///
+    /// player.class_select(ed_5::Class::Fighter);
+    ///
+    /// assert_eq!(player.get_armor_penalty(), HashSet::from([
+    ///     ed_5::ArmorPenalty::Strength,
+    ///     ed_5::ArmorPenalty::StealthDisadvantage
+    /// ]));
+    /// ```
+    pub fn get_armor_penalty(&self) -> HashSet<ArmorPenalty> {
+        let mut penalty = HashSet::new();
+        if self.equipped_shield && !self.armor.contains(&Armor::Shield) {
+            penalty.insert(ArmorPenalty::NotProficient);
+        }
+        if let Some(armor) = self.equipped_armor {
+            if !self.armor.contains(&armor.get_category()) {
+                penalty.insert(ArmorPenalty::NotProficient);
+            }
+            if self.get_ability_score(AP::STR) < armor.get_strength() {
+                penalty.insert(ArmorPenalty::Strength);
+            }
+            if armor.get_stealth_disadvantage() {
+                penalty.insert(ArmorPenalty::StealthDisadvantage);
+            }
+        }
+        penalty
+    }
+
+    /// Return attack roll bonus with a weapon, proficiency
+    /// bonus is only added when proficient
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .ap_point_buy([15,12,14,10,12,8])
+    ///     .class_select(ed_5::Class::Fighter);
+    ///
+    /// // STR modifier (2) + proficiency bonus (2)
+    /// assert_eq!(player.get_attack_bonus(Weapon::Longsword), 4);
+    /// // Finesse uses the higher of STR and DEX
+    /// assert_eq!(player.get_attack_bonus(Weapon::Rapier), 4);
+    /// // Ranged uses DEX
+    /// assert_eq!(player.get_attack_bonus(Weapon::Longbow), 3);
+    ///
+    /// player.class_select(ed_5::Class::Wizard);
+    ///
+    /// assert_eq!(player.get_attack_bonus(Weapon::Longsword), 2);
+    /// ```
+    pub fn get_attack_bonus(&self, weapon: Weapon) -> isize {
+        let proficiency = match self.is_proficient(weapon.clone()) {
+            true => self.get_proficiency_bonus(),
+            false => 0
+        };
+        self.weapon_modifier(&weapon) + proficiency
+    }
+
+    /// Return damage roll of a weapon, with ability modifier
+    /// and damage type
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.ap_point_buy([15,8,14,10,12,10]);
+    ///
+    /// assert_eq!(player.get_damage_expression(Weapon::Greatsword), "2d6+2 Slashing");
+    /// assert_eq!(player.get_damage_expression(Weapon::Shortbow), "1d6-1 Piercing");
+    /// assert_eq!(player.get_damage_expression(Weapon::Blowgun), "1-1 Piercing");
+    /// assert_eq!(player.get_damage_expression(Weapon::Net), "0");
+    /// ```
+    pub fn get_damage_expression(&self, weapon: Weapon) -> String {
+        let stat = weapon.get_stat();
+        let damage_type = match stat.damage_type {
+            Some(damage_type) => damage_type,
+            None => return "0".to_string()
+        };
+        let (count, sides) = stat.damage;
+        let dice = match sides {
+            1 => count.to_string(),
+            _ => format!("{}d{}", count, sides)
+        };
+        let modifier = match self.weapon_modifier(&weapon) {
+            0 => String::new(),
+            modifier => format!("{:+}", modifier)
+        };
+        format!("{}{} {:?}", dice, modifier, damage_type)
+    }
+
+    /// Return skill bonus, ability modifier plus proficiency
+    /// bonus when proficient (doubled with expertise)
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Elf::Wood)
+    ///     .ap_point_buy([8,14,14,15,12,8]);
+    ///
+    /// assert_eq!(player.get_skill_bonus(Skill::Stealth), 3);
+    /// assert_eq!(player.get_skill_bonus(Skill::Perception), 3);
+    /// ```
+    pub fn get_skill_bonus(&self, skill: Skill) -> isize {
+        let mut bonus = self.get_ability_modifier(skill.get_ability());
+        if self.skill.contains(&skill) {
+            bonus += self.get_proficiency_bonus();
+            if self.expertise.contains(&skill) {
+                bonus += self.get_proficiency_bonus();
+            }
+        }
+        bonus
+    }
+
+    /// Return bonus of every skill in declaration order
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.ap_point_buy([14,8,8,8,8,8]);
+    ///
+    /// let bonuses = player.get_all_skill_bonus();
+    ///
+    /// assert_eq!(bonuses.len(), 18);
+    /// assert_eq!(bonuses[0], (Skill::Athletics, 2));
+    /// ```
+    pub fn get_all_skill_bonus(&self) -> Vec<(Skill, isize)> {
+        Skill::get_all().into_iter()
+            .map(|skill| {
+                let bonus = self.get_skill_bonus(skill.clone());
+                (skill, bonus)
+            })
+            .collect()
+    }
+
+    /// Return saving throw bonus, ability modifier plus
+    /// proficiency bonus when proficient
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Cleric)
+    ///     .ap_point_buy([8,13,14,12,15,10]);
+    ///
+    /// assert_eq!(player.get_saving_throw_bonus(AP::WIS), 4);
+    /// assert_eq!(player.get_saving_throw_bonus(AP::DEX), 1);
+    /// ```
+    pub fn get_saving_throw_bonus(&self, ap: AP) -> isize {
+        let mut bonus = self.get_ability_modifier(ap);
+        if self.saving_throw.contains(&ap) {
+            bonus += self.get_proficiency_bonus();
+        }
+        bonus
+    }
+
+    /// Return saving throw bonus of all abilities
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .class_select(ed_5::Class::Ranger)
+    ///     .ap_point_buy([12,15,13,8,14,10]);
+    ///
+    /// assert_eq!(player.get_all_saving_throw_bonus(), [3,4,1,-1,2,0]);
+    /// ```
+    pub fn get_all_saving_throw_bonus(&self) -> [isize; 6] {
+        AP::get_all().map(|ap| self.get_saving_throw_bonus(ap))
+    }
+
+    /// Return racial traits, including traits picked
+    /// from race options
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Tiefling::Basic);
+    ///
+    /// assert_eq!(player.get_all_traits(), &[
+    ///     ed_5::RacialTrait::Darkvision,
+    ///     ed_5::RacialTrait::HellishResistance,
+    ///     ed_5::RacialTrait::InfernalLegacy
+    /// ]);
+    /// ```
+    pub fn get_all_traits(&self) -> &[RacialTrait] {
+        &self.traits
+    }
+
+    /// Return darkvision range in feet, 0 without darkvision
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Elf::Drow);
+    ///
+    /// assert_eq!(player.get_darkvision(), 120);
+    /// ```
+    pub fn get_darkvision(&self) -> usize {
+        self.trait_effect()
+            .filter_map(|effect| match effect {
+                TraitEffect::Darkvision(range) => Some(range),
+                _ => None
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return swimming speed in feet, 0 without swimming
+    /// speed
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Elf::Sea);
+    ///
+    /// assert_eq!(player.get_swim_speed(), 30);
+    /// ```
+    pub fn get_swim_speed(&self) -> usize {
+        self.trait_effect()
+            .filter_map(|effect| match effect {
+                TraitEffect::SwimSpeed(speed) => Some(speed),
+                _ => None
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return HashSet of damage resistances
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Dragonborn::Basic)
+    ///     .race_use_trait(ed_5::RacialTrait::DraconicAncestrySilver);
+    ///
+    /// assert_eq!(player.get_all_resistance(), HashSet::from([DamageType::Cold]));
+    /// ```
+    pub fn get_all_resistance(&self) -> HashSet<DamageType> {
+        self.trait_effect()
+            .filter_map(|effect| match effect {
+                TraitEffect::Resistance(damage) => Some(damage),
+                _ => None
+            })
+            .collect()
+    }
+
+    /// Return HashSet of conditions with advantage on
+    /// saving throws against them
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Dwarf::Duegar);
+    ///
+    /// assert_eq!(player.get_all_condition_advantage(), HashSet::from([
+    ///     Condition::Poisoned,
+    ///     Condition::Charmed,
+    ///     Condition::Paralyzed
+    /// ]));
+    /// ```
+    pub fn get_all_condition_advantage(&self) -> HashSet<Condition> {
+        self.trait_effect()
+            .filter_map(|effect| match effect {
+                TraitEffect::Advantage(condition) => Some(condition),
+                _ => None
+            })
+            .collect()
+    }
+
+    /// Return innate spells castable at current level
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Tiefling::Basic);
+    ///
+    /// assert_eq!(player.get_innate_spell(), vec!["Thaumaturgy"]);
+    ///
+    /// player.add_xp(900);
+    ///
+    /// assert_eq!(player.get_innate_spell(), vec!["Thaumaturgy", "Hellish Rebuke"]);
+    /// ```
+    pub fn get_innate_spell(&self) -> Vec<&'static str> {
+        let level = self.get_level();
+        self.trait_effect()
+            .filter_map(|effect| match effect {
+                TraitEffect::InnateSpell { spell, level: req }
+                    if req <= level => Some(spell),
+                _ => None
+            })
+            .collect()
+    }
+
+    /// Return reference to a HashSet of weapon proficiencies
+    /// from race and class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Elf::High)
+    ///     .class_select(ed_5::Class::Cleric);
+    ///
+    /// assert!(player.get_all_weap().contains(&Weapon::Longsword));
+    /// assert!(player.get_all_weap().contains(&Weapon::Mace));
+    /// ```
+    pub fn get_all_weap(&self) -> &HashSet<Weapon> {
+        &self.weap
+    }
+
+    /// Return reference to a HashSet of weapon categories
+    /// granted as a whole
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Rogue);
+    ///
+    /// assert!(player.get_all_weap_category().contains(&ed_5::WeaponCategory::Simple));
+    /// assert!(!player.get_all_weap_category().contains(&ed_5::WeaponCategory::Martial));
+    /// ```
+    pub fn get_all_weap_category(&self) -> &HashSet<WeaponCategory> {
+        &self.weap_category
+    }
+
+    /// Return whether character is proficient with a weapon,
+    /// either from its category or on its own
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Elf::High)
+    ///     .class_select(ed_5::Class::Wizard);
+    ///
+    /// assert!(player.is_proficient(Weapon::Dagger));
+    /// assert!(player.is_proficient(Weapon::Longbow));
+    /// assert!(!player.is_proficient(Weapon::Mace));
+    /// ```
+    pub fn is_proficient(&self, weapon: Weapon) -> bool {
+        self.weap_category.contains(&weapon.get_stat().category)
+            || self.weap.contains(&weapon)
+    }
+
+    /// Return reference to a HashSet of armor proficiencies
+    /// from race and class
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player
+    ///     .race_select(ed_5::Dwarf::Duegar)
+    ///     .class_select(ed_5::Class::Fighter);
+    ///
+    /// assert_eq!(player.get_all_armor(), &HashSet::from([
+    ///     Armor::Light,
+    ///     Armor::Medium,
+    ///     Armor::Heavy,
+    ///     Armor::Shield
+    /// ]));
+    /// ```
+    pub fn get_all_armor(&self) -> &HashSet<Armor> {
+        &self.armor
+    }
+
+    /// Return reference to a HashSet of skill proficiencies
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.race_select(ed_5::Elf::High);
+    ///
+    /// assert!(player.get_all_skill().contains(&Skill::Perception));
+    /// ```
+    pub fn get_all_skill(&self) -> &HashSet<Skill> {
+        &self.skill
+    }
+
+    /// Return reference to a HashSet of tool proficiencies
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let player = ed_5::Character::build();
+    ///
+    /// assert!(player.get_all_tools().is_empty());
+    /// ```
+    pub fn get_all_tools(&self) -> &HashSet<Tools> {
+        &self.tools
+    }
+
+    /// Return reference to a HashSet of saving throw
+    /// proficiencies
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Ranger);
+    ///
+    /// assert_eq!(player.get_all_saving_throw(), &HashSet::from([
+    ///     AP::STR,
+    ///     AP::DEX
+    /// ]));
+    /// ```
+    pub fn get_all_saving_throw(&self) -> &HashSet<AP> {
+        &self.saving_throw
+    }
+
+    /// Return reference to a HashSet of known languages
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    /// use std::collections::HashSet;
     ///
     /// let mut player = ed_5::Character::build();
     ///
@@ -542,6 +4377,22 @@ impl<'a> Character<'a> {
         &self.lang
     }
 
+    /// Return reference to a HashSet of language categories
+    /// language points can be spent on
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let player = ed_5::Character::build();
+    ///
+    /// assert!(player.get_lang_rule().contains(&ed_5::LanguageCategory::Exotic));
+    /// assert!(!player.get_lang_rule().contains(&ed_5::LanguageCategory::Secret));
+    /// ```
+    pub fn get_lang_rule(&self) -> &HashSet<LanguageCategory> {
+        &self.lang_rule
+    }
+
     /// Return reference to array of current ap stat 
     /// without/bofore applying seqeunce
     /// (Refer to ap_assign_seq() method)
@@ -576,22 +4427,25 @@ impl<'a> Character<'a> {
     /// 
     /// assert_eq!(player.get_ap_seq(), Ok([0,1,2,3,4,5]));
     /// ```
-    pub fn get_ap_seq(&self) -> Result<[usize; 6], &str> {
-        match &self.ap_seq {
-            Ok(seq) => {
-                let mut val = seq.iter();
-                let ap_seq_array = [
-                    *val.next().unwrap(),
-                    *val.next().unwrap(),
-                    *val.next().unwrap(),
-                    *val.next().unwrap(),
-                    *val.next().unwrap(),
-                    *val.next().unwrap(),
-                ];
-                Ok(ap_seq_array)
-            }
-            Err(_) => Err("Error: No usable sequence found")
-        }
+    pub fn get_ap_seq(&self) -> Result<[usize; 6], CharacterError> {
+        self.ap_seq.clone()
+    }
+
+    /// Return adjustments from items, curses or other effects
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.ap_adjust(AP::CON, -1);
+    ///
+    /// assert_eq!(player.get_ap_adjustment(), &[0,0,-1,0,0,0]);
+    /// ```
+    pub fn get_ap_adjustment(&self) -> &[isize; 6] {
+        &self.ap_adjustment
     }
 
     /// Return class name as String
@@ -606,7 +4460,26 @@ impl<'a> Character<'a> {
     /// assert_eq!(player.get_class(), &ed_5::Class::Unknown);
     /// ```
     pub fn get_class(&self) -> &Class {
-        &self.class
+        &self.class[0].class
+    }
+
+    /// Return every class taken with its level, starting
+    /// class first
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    ///
+    /// let mut player = ed_5::Character::build();
+    ///
+    /// player.class_select(ed_5::Class::Ranger);
+    ///
+    /// assert_eq!(player.get_all_class(), vec![(ed_5::Class::Ranger, 1)]);
+    /// ```
+    pub fn get_all_class(&self) -> Vec<(Class, usize)> {
+        self.class.iter()
+            .map(|entry| (entry.class, entry.level))
+            .collect()
     }
 
     /// Print debug information
@@ -636,10 +4509,20 @@ impl<'a> Character<'a> {
     /* -----------
        | Private |
        ----------- */
+    // Ability scores before adjustments from items and
+    // other effects, which the maximum of 20 applies to
+    fn unadjusted_ability_score(&self) -> [isize; 6] {
+        let mut ability_scores = [0,0,0,0,0,0];
+        self.calculate_race_default(&mut ability_scores);
+        self.calculate_race_user(&mut ability_scores);
+        self.calculate_base_ap(&mut ability_scores);
+        self.calculate_improvement(&mut ability_scores);
+        ability_scores
+    }
+
     // Calculate points assigned from race by default
-    fn calculate_race_default(&self, ability_scores: &mut [usize; 6]) {
-        if self.buffer_race.is_some() {
-            let buff_ptr = &self.buffer_race.as_ref().unwrap();
+    fn calculate_race_default(&self, ability_scores: &mut [isize; 6]) {
+        if let Some(buff_ptr) = &self.buffer_race {
             let mut race_ap = buff_ptr.ap.iter();
             for score in ability_scores {
                 *score += race_ap.next().unwrap();
@@ -648,19 +4531,100 @@ impl<'a> Character<'a> {
     }
 
     // Calculate points assigned from race by user
-    fn calculate_race_user(&self, ability_scores: &mut [usize; 6]) {
-        for used_ability in &self.race_used_ability {
-            ability_scores[used_ability.get_index()] += 1;
+    fn calculate_race_user(&self, ability_scores: &mut [isize; 6]) {
+        let grant = self.race_ap_grant();
+        for (used_ability, index) in &self.race_used_ability {
+            ability_scores[used_ability.get_index()] += grant[*index].amount;
         }
     }
 
     // Add base points to ablity scores
-    fn calculate_base_ap(&self, ability_scores: &mut [usize; 6])  {
+    fn calculate_base_ap(&self, ability_scores: &mut [isize; 6])  {
         // Add base_ap to ability scores
         let mut ptr = ability_scores.iter_mut();
         for point in self.base_ap {
-            *ptr.next().unwrap() += point;
+            *ptr.next().unwrap() += point as isize;
+        }
+    }
+
+    // Add signed adjustments from feats, items and
+    // other effects, ignoring the maximum of 20
+    fn calculate_adjustment(&self, ability_scores: &mut [isize; 6]) {
+        let feat = self.feat_effect().filter_map(|effect| match effect {
+            FeatEffect::AbilityAdjust(ap, amount) => Some((ap, amount)),
+            _ => None
+        });
+        for (ap, amount) in feat {
+            let score = &mut ability_scores[ap.get_index()];
+            *score = score.saturating_add(amount);
+        }
+        for (score, amount) in ability_scores.iter_mut().zip(self.ap_adjustment) {
+            *score = score.saturating_add(amount);
+        }
+    }
+
+    // Mechanical effects of all racial traits
+    fn trait_effect(&self) -> impl Iterator<Item = TraitEffect> + '_ {
+        self.get_all_traits().iter().flat_map(|t| t.get_effect())
+    }
+
+    // Ability modifier of a score, rounded down
+    fn ability_modifier(score: usize) -> isize {
+        (score as isize - 10).div_euclid(2)
+    }
+
+    // Proficiency bonus of a level
+    fn proficiency_bonus(level: usize) -> isize {
+        2 + (level.max(1) as isize - 1) / 4
+    }
+
+    // Ability modifier used with a weapon, DEX for ranged,
+    // STR for melee and the higher one for finesse
+    fn weapon_modifier(&self, weapon: &Weapon) -> isize {
+        let stat = weapon.get_stat();
+        let str = self.get_ability_modifier(AP::STR);
+        let dex = self.get_ability_modifier(AP::DEX);
+        if stat.property.contains(&WeaponProperty::Finesse) {
+            str.max(dex)
+        }
+        else if stat.range == WeaponRange::Ranged {
+            dex
+        }
+        else {
+            str
+        }
+    }
+
+    // Passive score of a skill
+    fn passive_skill(&self, skill: Skill) -> isize {
+        let bonus: isize = match skill {
+            Skill::Perception | Skill::Investigation => self.feat_effect()
+                .map(|effect| match effect {
+                    FeatEffect::PassiveBonus(bonus) => bonus,
+                    _ => 0
+                })
+                .sum(),
+            _ => 0
+        };
+        10 + self.get_skill_bonus(skill) + bonus
+    }
+
+    // Validate sequence, every index from 0 to 5 must appear once
+    fn validate_seq(sequence: [usize; 6]) -> Result<[usize; 6], CharacterError> {
+        let mut set = BTreeSet::new();
+        for index in sequence {
+            if index > 5 {
+                return Err(CharacterError::SequenceOutOfRange {
+                    sequence, index
+                });
+            }
+            if !set.insert(index) {
+                return Err(CharacterError::DuplicateSequence {
+                    sequence, index
+                });
+            }
         }
+        Ok(sequence)
     }
 
     // Calculate base points
@@ -669,9 +4633,8 @@ impl<'a> Character<'a> {
         self.base_ap = [0,0,0,0,0,0];
         // Insert rolled stat to base_ap according to sequence
         if let Ok(seq) = &self.ap_seq {
-            let mut base_ap_ptr = self.base_ap.iter_mut();
-            for val in seq {
-                *base_ap_ptr.next().unwrap() = self.ap_unassigned[*val];
+            for (val, index) in self.ap_unassigned.iter().zip(seq) {
+                self.base_ap[*index] = *val;
             }
         }
         self
@@ -679,94 +4642,630 @@ impl<'a> Character<'a> {
 
     /// Initialise race ap
     fn init_race_ap(&mut self) -> &mut Self {
-        if self.buffer_race.is_some() {
-            let buff_ptr = &self.buffer_race.as_ref().unwrap();
-            self.race_usable_ap = buff_ptr.ap[6] - self.race_used_ability.len();
+        let grant = self.race_ap_grant();
+        // Drop latest picks until every pick fits a bonus
+        loop {
+            let pick: Vec<(AP, Option<usize>)> = self.race_used_ability.iter()
+                .map(|(ap, index)| (*ap, Some(*index)))
+                .collect();
+            if let Some(index) = assign_ability(&grant, &pick) {
+                for (used, index) in self.race_used_ability.iter_mut().zip(index) {
+                    used.1 = index;
+                }
+                break;
+            }
+            self.race_used_ability.pop();
         }
+        self.race_usable_ap = grant.iter()
+            .map(|grant| grant.count)
+            .sum::<usize>()
+            .saturating_sub(self.race_used_ability.len());
         self
     }
 
-    // Initialise race languages
-    fn init_race_lang(&mut self) -> &mut Self {
-        if self.buffer_race.is_some() {
-            let buff_ptr = &self.buffer_race.as_ref().unwrap();
-            // Clear all languages
-            self.lang = HashSet::new();
-            // Initialise default race language(s)
-            for lang in &buff_ptr.lang {
-                self.lang.insert(lang.clone());
-            }
-            // Initialise manually assigned language(s)
-            for lang in self.race_used_lang.iter() {
-                self.lang.insert(lang.clone());
-            }
-            // Initialise usable language point(s)
-            self.lang_point = buff_ptr.lang_point - self.race_used_lang.len();
+    // Ability bonuses granted by race for the player to assign
+    fn race_ap_grant(&self) -> Vec<AbilityGrant> {
+        match &self.buffer_race {
+            Some(buff_ptr) => buff_ptr.choice.iter()
+                .filter_map(RaceChoice::get_ability)
+                .collect(),
+            None => Vec::new()
+        }
+    }
+
+    // Initialise languages from race and background
+    fn init_lang(&mut self) -> &mut Self {
+        // Clear all languages
+        self.lang = HashSet::new();
+        // Initialise default race language(s)
+        if let Some(buff_ptr) = &self.buffer_race {
+            self.lang.extend(buff_ptr.lang.iter().cloned());
         }
+        // Initialise secret language(s) taught by class
+        for entry in &self.class {
+            self.lang.extend(entry.class.get_lang());
+        }
+        // Initialise manually assigned language(s) and
+        // usable language point(s)
+        let choice = self.race_choice(RaceChoice::get_language);
+        self.lang_point = apply_choice(&mut self.race_used_lang, &choice, &mut self.lang);
+        // Drop background choices now known from race
+        self.background_used_lang.retain(|lang| !self.lang.contains(lang));
+        self.lang.extend(self.background_used_lang.iter().cloned());
+        self.background_lang_point = self.buffer_background.lang_point
+            .saturating_sub(self.background_used_lang.len());
         self
     }
 
     // Intialise weapons
-    fn init_race_weap(&mut self) -> &mut Self {
-        if self.buffer_race.is_some() {
-            let buff_ptr = &self.buffer_race.as_ref().unwrap();
-            // Clear all weapons
-            self.weap = HashSet::new();
-            // Initialize weapon profeciency from race
-            for weap in &buff_ptr.weap {
-                self.weap.insert(weap.clone());
+    fn init_weap(&mut self) -> &mut Self {
+        let mut prof: Vec<WeaponProf> = Vec::new();
+        // Initialize weapon profeciency from race
+        if let Some(buff_ptr) = &self.buffer_race {
+            prof.extend(buff_ptr.weap.iter().cloned());
+        }
+        // Initialize weapon profeciency from class
+        for (index, entry) in self.class.iter().enumerate() {
+            if index == 0 {
+                prof.extend(entry.class.get_weapon_prof());
+            }
+            else {
+                prof.extend(entry.class.get_multiclass_weapon_prof());
+            }
+        }
+        // Initialize weapon profeciency from feats
+        for effect in self.feat.iter().flat_map(|feat| feat.get_effect()) {
+            if let FeatEffect::WeaponProf(weap) = effect {
+                prof.push(weap);
             }
         }
+        // Clear all weapons, categories also grant their weapons
+        self.weap = HashSet::new();
+        self.weap_category = HashSet::new();
+        for weap in prof {
+            if let WeaponProf::Category(category) = &weap {
+                self.weap_category.insert(*category);
+            }
+            self.weap.extend(weap.get_weapon());
+        }
+        // Initialize weapon profeciency chosen from race
+        let choice = self.race_choice(RaceChoice::get_weapon);
+        self.race_weap_point = apply_choice(&mut self.race_used_weap, &choice, &mut self.weap);
+        self
+    }
+
+    // Initialise racial traits, with traits picked
+    // from race options
+    fn init_race_trait(&mut self) -> &mut Self {
+        let mut known: HashSet<RacialTrait> = HashSet::new();
+        if let Some(buff_ptr) = &self.buffer_race {
+            known.extend(buff_ptr.traits.iter().copied());
+        }
+        let choice = self.race_choice(RaceChoice::get_trait);
+        self.race_trait_point = apply_choice(&mut self.race_used_trait, &choice, &mut known);
+        self.traits = match &self.buffer_race {
+            Some(buff_ptr) => buff_ptr.traits.clone(),
+            None => Vec::new()
+        };
+        self.traits.extend(self.race_used_trait.iter().copied());
         self
     }
 
+    // Options of every race choice of a kind
+    fn race_choice<T>(&self, option: fn(&RaceChoice) -> Option<Vec<T>>) -> Vec<Vec<T>> {
+        match &self.buffer_race {
+            Some(buff_ptr) => buff_ptr.choice.iter().filter_map(option).collect(),
+            None => Vec::new()
+        }
+    }
+
     // Intialise armor
-    fn init_race_armor(&mut self) -> &mut Self {
-        if self.buffer_race.is_some() {
-            let buff_ptr = &self.buffer_race.as_ref().unwrap();
-            // Clear all weapons
-            self.armor = HashSet::new();
-            // Initialize weapon profeciency from race
+    fn init_armor(&mut self) -> &mut Self {
+        // Clear all armor
+        self.armor = HashSet::new();
+        // Initialize armor profeciency from race
+        if let Some(buff_ptr) = &self.buffer_race {
             for armor in &buff_ptr.armor {
                 self.armor.insert(armor.clone());
             }
         }
+        // Initialize armor profeciency from class
+        for (index, entry) in self.class.iter().enumerate() {
+            if index == 0 {
+                self.armor.extend(entry.class.get_armor_prof());
+            }
+            else {
+                self.armor.extend(entry.class.get_multiclass_armor_prof());
+            }
+        }
+        // Initialize armor profeciency from feats
+        for effect in self.feat.iter().flat_map(|feat| feat.get_effect()) {
+            if let FeatEffect::ArmorProf(armor) = effect {
+                self.armor.insert(armor);
+            }
+        }
         self
     }
 
     // Initialise skills
-    fn init_race_skill(&mut self) -> &mut Self {
-        if self.buffer_race.is_some() {
-            let buff_ptr = &self.buffer_race.as_ref().unwrap();
-            // Clear all skills
-            self.skill = HashSet::new();
-            // Initialize skill profeciency from race
+    fn init_skill(&mut self) -> &mut Self {
+        // Clear all skills
+        self.skill = HashSet::new();
+        // Initialize skill profeciency from race
+        if let Some(buff_ptr) = &self.buffer_race {
             for skill in &buff_ptr.skill {
                 self.skill.insert(skill.clone());
             }
         }
+        // Initialize skill profeciency chosen from race
+        self.skill.extend(self.race_used_skill.iter().cloned());
+        // Initialize skill profeciency from background
+        for skill in &self.buffer_background.skill {
+            self.skill.insert(skill.clone());
+        }
+        // Initialize skill profeciency from feats
+        for effect in self.feat.iter().flat_map(|feat| feat.get_effect()) {
+            if let FeatEffect::SkillProf(skill) = effect {
+                self.skill.insert(skill);
+            }
+        }
+        // Initialize skill profeciency chosen from class
+        for entry in &self.class {
+            for skill in &entry.used_skill {
+                self.skill.insert(skill.clone());
+            }
+        }
+        // Drop expertise in skills no longer proficient
+        self.expertise.retain(|skill| self.skill.contains(skill));
+        self
+    }
+
+    // Initialise skills chosen from race
+    fn init_race_skill(&mut self) -> &mut Self {
+        // Drop choices now granted by background or no
+        // longer offered, then initialise usable skill point(s)
+        let mut known: HashSet<Skill> = self.buffer_background.skill.iter()
+            .cloned()
+            .collect();
+        let choice = self.race_choice(RaceChoice::get_skill);
+        self.race_skill_point = apply_choice(&mut self.race_used_skill, &choice, &mut known);
+        self
+    }
+
+    // Initialise feat and ability score improvement slots,
+    // dropping latest choices when slots are lost, and
+    // everything granted by feats
+    fn init_improvement(&mut self) -> &mut Self {
+        let race_slot = match &self.buffer_race {
+            Some(buff_ptr) => buff_ptr.feat_point,
+            None => 0
+        };
+        let asi_slot: usize = self.class.iter()
+            .map(|entry| entry.class.get_asi_level().iter()
+                .filter(|level| **level <= entry.level)
+                .count())
+            .sum();
+        let slot = race_slot + asi_slot;
+        self.asi.truncate(asi_slot);
+        self.feat.truncate(slot - self.asi.len());
+        self.feat_point = slot - self.asi.len() - self.feat.len();
+        // Feats use race slots first
+        self.asi_point = asi_slot.saturating_sub(
+            self.asi.len() + self.feat.len().saturating_sub(race_slot)
+        );
+        self.init_weap()
+            .init_armor()
+            .init_saving_throw()
+            .init_skill()
+            .init_speed()
+    }
+
+    // Mechanical effects of all feats
+    fn feat_effect(&self) -> impl Iterator<Item = FeatEffect> + '_ {
+        self.feat.iter().flat_map(|feat| feat.get_effect())
+    }
+
+    // Check prerequisites of a feat
+    fn check_feat_prerequisite(&self, feat: &Feat) -> Result<(), CharacterError> {
+        let scores = self.get_all_ability_score();
+        for prerequisite in feat.get_prerequisite() {
+            let met = match &prerequisite {
+                FeatPrerequisite::Ability(options) => options.iter()
+                    .any(|(ap, score)| scores[ap.get_index()] >= *score),
+                FeatPrerequisite::ArmorProf(armor) => self.armor.contains(armor),
+                FeatPrerequisite::Spellcasting => self.can_cast_spell(),
+                FeatPrerequisite::Level(level) => self.get_level() >= *level,
+            };
+            if !met {
+                return Err(CharacterError::FeatRequirement {
+                    feat: feat.clone(), prerequisite
+                });
+            }
+        }
+        Ok(())
+    }
+
+    // Whether at least one spell can be cast from class,
+    // race or feat
+    fn can_cast_spell(&self) -> bool {
+        self.class.iter().any(|entry| matches!(
+            entry.class.get_spellcasting_level(),
+            Some(level) if level <= entry.level
+        ))
+            || !self.get_innate_spell().is_empty()
+            || self.feat_effect()
+                .any(|effect| matches!(effect, FeatEffect::Spellcasting(_)))
+    }
+
+    // Add ability score increases from ability score
+    // improvements and feats, up to 20
+    fn calculate_improvement(&self, ability_scores: &mut [isize; 6]) {
+        let asi = self.asi.iter().flat_map(|improvement| improvement.get_ability());
+        let feat = self.feat_effect().filter_map(|effect| match effect {
+            FeatEffect::AbilityIncrease(ap) => Some(ap),
+            _ => None
+        });
+        for ap in asi.chain(feat) {
+            let score = &mut ability_scores[ap.get_index()];
+            if *score < 20 {
+                *score += 1;
+            }
+        }
+    }
+
+    // Initialise skills chosen from class
+    fn init_class_skill(&mut self) -> &mut Self {
+        let granted_skill = self.granted_skill();
+        for index in 0..self.class.len() {
+            // Drop choices now granted by race or background
+            for skill in &granted_skill {
+                self.class[index].used_skill.remove(skill);
+            }
+            // Drop choices outside of class skill list no
+            // longer covered by duplicates, last listed first
+            let (duplicate, other) = self.class_skill_count(index);
+            let entry = &mut self.class[index];
+            let class_skill = entry.class.get_skill();
+            let mut excess = other.saturating_sub(duplicate);
+            for skill in Skill::get_all().iter().rev() {
+                if excess == 0 {
+                    break;
+                }
+                if !class_skill.contains(skill) && entry.used_skill.remove(skill) {
+                    excess -= 1;
+                }
+            }
+            // Initialise usable skill point(s)
+            let prof_point = if index == 0 {
+                entry.class.get_prof_point()
+            }
+            else {
+                entry.class.get_multiclass_prof_point()
+            };
+            entry.skill_point = prof_point
+                .saturating_sub(entry.used_skill.len());
+        }
+        self
+    }
+
+    // Choose skill of class at index
+    fn try_class_index_use_skill(&mut self, index: usize, skill: Skill)
+        -> Result<&mut Self, CharacterError> {
+        if self.class[index].skill_point == 0 {
+            return Err(CharacterError::NoClassSkillPoint);
+        }
+        if self.skill.contains(&skill) {
+            return Err(CharacterError::SkillKnown(skill));
+        }
+        if !self.class[index].class.get_skill().contains(&skill)
+            && self.class_skill_replacement(index) == 0 {
+            return Err(CharacterError::SkillNotAvailable(skill));
+        }
+        self.class[index].used_skill.insert(skill);
+        Ok(self.init_class_skill().init_skill())
+    }
+
+    // Number of skills outside of class skill list that
+    // can still be chosen
+    fn class_skill_replacement(&self, index: usize) -> usize {
+        let (duplicate, other) = self.class_skill_count(index);
+        duplicate.saturating_sub(other)
+    }
+
+    // Skills granted by race and background
+    fn granted_skill(&self) -> Vec<Skill> {
+        let mut granted_skill = match &self.buffer_race {
+            Some(buff_ptr) => buff_ptr.skill.clone(),
+            None => Vec::new()
+        };
+        for skill in &self.buffer_background.skill {
+            if !granted_skill.contains(skill) {
+                granted_skill.push(skill.clone());
+            }
+        }
+        granted_skill
+    }
+
+    // Count class skills already granted by race or
+    // background, and chosen skills outside of class
+    // skill list
+    // A granted skill only counts for the first class
+    // listing it
+    fn class_skill_count(&self, index: usize) -> (usize, usize) {
+        let entry = &self.class[index];
+        let class_skill = entry.class.get_skill();
+        let duplicate = self.granted_skill().iter()
+            .filter(|skill| class_skill.contains(skill))
+            .filter(|skill| self.class[..index].iter()
+                .all(|previous| !previous.class.get_skill().contains(skill)))
+            .count();
+        let other = entry.used_skill.iter()
+            .filter(|skill| !class_skill.contains(skill))
+            .count();
+        (duplicate, other)
+    }
+
+    // Position of class in taken classes
+    fn class_index(&self, class: &Class) -> Option<usize> {
+        self.class.iter().position(|entry| &entry.class == class)
+    }
+
+    // Check ability scores required to multiclass
+    fn check_multiclass_req(&self, class: Class) -> Result<(), CharacterError> {
+        let scores = self.get_all_ability_score();
+        let requirement = class.get_multiclass_req();
+        let met = |(ap, score): &(AP, usize)| scores[ap.get_index()] >= *score;
+        if requirement.is_empty()
+            || requirement.iter().any(|option| option.iter().all(met)) {
+            return Ok(())
+        }
+        let (ability, required) = requirement[0].iter()
+            .find(|req| !met(req))
+            .copied()
+            .unwrap();
+        Err(CharacterError::MulticlassRequirement { class, ability, required })
+    }
+
+    // Initialise everything granted by class
+    fn init_class(&mut self) -> &mut Self {
+        self.init_improvement()
+            .init_lang()
+            .init_weap()
+            .init_armor()
+            .init_tools()
+            .init_saving_throw()
+            .init_class_skill()
+            .init_skill()
+            .init_hit_point()
+    }
+
+    // Initialise tools
+    fn init_tools(&mut self) -> &mut Self {
+        // Clear all tools
+        self.tools = HashSet::new();
+        // Initialize tool profeciency from race
+        if let Some(buff_ptr) = &self.buffer_race {
+            self.tools.extend(buff_ptr.tools.iter().cloned());
+        }
+        // Initialize tool profeciency from class
+        for (index, entry) in self.class.iter().enumerate() {
+            if index == 0 {
+                self.tools.extend(entry.class.get_tools_prof());
+            }
+            else {
+                self.tools.extend(entry.class.get_multiclass_tools_prof());
+            }
+        }
+        // Initialize tool profeciency from background
+        self.tools.extend(self.buffer_background.tools.iter().cloned());
+        // Initialise chosen tools from race, class and background
+        let race_choice = self.race_choice(RaceChoice::get_tools);
+        self.race_tool_point = apply_choice(
+            &mut self.race_used_tools, &race_choice, &mut self.tools
+        );
+        for index in 0..self.class.len() {
+            let entry = &mut self.class[index];
+            let choice = tool_option(&if index == 0 {
+                entry.class.get_tool_choice()
+            }
+            else {
+                entry.class.get_multiclass_tool_choice()
+            });
+            entry.tool_point = apply_choice(
+                &mut entry.used_tools, &choice, &mut self.tools
+            );
+        }
+        self.background_tool_point = apply_choice(
+            &mut self.background_used_tools,
+            &tool_option(&self.buffer_background.tool_choice),
+            &mut self.tools
+        );
+        self
+    }
+
+    // Check tool can be chosen from choices, with
+    // tools already chosen from them
+    fn check_tool_choice(&self, choice: &[Vec<Tools>], used: &HashSet<Tools>,
+        tool: &Tools) -> Result<(), CharacterError> {
+        if self.tools.contains(tool) {
+            return Err(CharacterError::ToolKnown(tool.clone()));
+        }
+        if used.len() >= choice.len() {
+            return Err(CharacterError::NoToolPoint);
+        }
+        if !fits_choice(choice, used.iter().chain([tool])) {
+            return Err(CharacterError::ToolNotAvailable(tool.clone()));
+        }
+        Ok(())
+    }
+
+    // Choose tool of class at index
+    fn try_class_index_use_tool(&mut self, index: usize, tool: Tools)
+        -> Result<&mut Self, CharacterError> {
+        let choice = tool_option(&if index == 0 {
+            self.class[index].class.get_tool_choice()
+        }
+        else {
+            self.class[index].class.get_multiclass_tool_choice()
+        });
+        self.check_tool_choice(&choice, &self.class[index].used_tools, &tool)?;
+        self.class[index].used_tools.insert(tool);
+        Ok(self.init_tools())
+    }
+
+    // Initialise saving throws
+    fn init_saving_throw(&mut self) -> &mut Self {
+        // Clear all saving throws
+        self.saving_throw = HashSet::new();
+        // Initialize saving throw profeciency from starting class
+        self.saving_throw.extend(self.class[0].class.get_saving_throw_prof());
+        // Initialize saving throw profeciency from feats
+        for effect in self.feat.iter().flat_map(|feat| feat.get_effect()) {
+            if let FeatEffect::SavingThrow(ap) = effect {
+                self.saving_throw.insert(ap);
+            }
+        }
+        self
+    }
+
+    // Initialise hit die value of every level
+    fn init_hit_point(&mut self) -> &mut Self {
+        for index in 0..self.class.len() {
+            let entry = &mut self.class[index];
+            let hit_die = entry.class.get_hit_die();
+            if hit_die == 0 {
+                entry.hp_roll = Vec::new();
+                continue
+            }
+            entry.hp_roll.truncate(entry.level);
+            while entry.hp_roll.len() < entry.level {
+                // Maximum hit die at first character level
+                let roll = if index == 0 && entry.hp_roll.is_empty() {
+                    hit_die
+                }
+                else {
+                    match self.hp_method {
+                        HitPointMethod::Average => hit_die / 2 + 1,
+                        HitPointMethod::Roll => dice::roll_die(hit_die)
+                    }
+                };
+                entry.hp_roll.push(roll);
+            }
+        }
         self
     }
 
     // Initialize speed
     fn init_speed(&mut self) -> &mut Self {
-        if self.buffer_race.is_some() {
-            let buff_ptr = &self.buffer_race.as_ref().unwrap();
-            self.speed = buff_ptr.speed;
+        if let Some(buff_ptr) = &self.buffer_race {
+            let bonus: usize = self.feat_effect()
+                .map(|effect| match effect {
+                    FeatEffect::Speed(speed) => speed,
+                    _ => 0
+                })
+                .sum();
+            self.speed = buff_ptr.speed + bonus;
         }
         self
     }
 
     // Initialize size
     fn init_size(&mut self) -> &mut Self {
-        if self.buffer_race.is_some() {
-            let buff_ptr = &self.buffer_race.as_ref().unwrap();
+        if let Some(buff_ptr) = &self.buffer_race {
             self.size = buff_ptr.size.clone();
         }
         self
     }
 }
 
+// Assign every pick to an option by backtracking, trying
+// options in the order given for each pick, with fits
+// checking an option for the next pick against earlier ones
+fn solve_pick(pick: usize, order: &dyn Fn(usize) -> Vec<usize>,
+    fits: &dyn Fn(&[usize], usize) -> bool) -> Option<Vec<usize>> {
+    fn solve(pick: usize, order: &dyn Fn(usize) -> Vec<usize>,
+        fits: &dyn Fn(&[usize], usize) -> bool, assigned: &mut Vec<usize>) -> bool {
+        if assigned.len() == pick {
+            return true;
+        }
+        for index in order(assigned.len()) {
+            if !fits(assigned, index) {
+                continue;
+            }
+            assigned.push(index);
+            if solve(pick, order, fits, assigned) {
+                return true;
+            }
+            assigned.pop();
+        }
+        false
+    }
+    let mut assigned = Vec::with_capacity(pick);
+    solve(pick, order, fits, &mut assigned).then_some(assigned)
+}
+
+// Match picked abilities to race ability bonuses, keeping
+// the previous bonus of a pick whenever possible
+fn assign_ability(grant: &[AbilityGrant], pick: &[(AP, Option<usize>)])
+    -> Option<Vec<usize>> {
+    solve_pick(
+        pick.len(),
+        &|next| {
+            let previous = pick[next].1.filter(|index| *index < grant.len());
+            previous.into_iter()
+                .chain((0..grant.len()).filter(|index| Some(*index) != previous))
+                .collect()
+        },
+        &|assigned, index| {
+            let (ability, _) = &pick[assigned.len()];
+            let bonus = &grant[index];
+            let taken = assigned.iter().filter(|other| **other == index).count();
+            let clash = assigned.iter().zip(pick).any(|(other, (other_ap, _))| {
+                other_ap == ability
+                    && (*other == index || !bonus.stack || !grant[*other].stack)
+            });
+            bonus.allows(*ability) && taken < bonus.count && !clash
+        }
+    )
+}
+
+// Whether every pick can be matched to a different choice,
+// choices given as their options
+fn fits_choice<'a, T: PartialEq + 'a>(choice: &[Vec<T>],
+    pick: impl IntoIterator<Item = &'a T>) -> bool {
+    let pick: Vec<&T> = pick.into_iter().collect();
+    solve_pick(
+        pick.len(),
+        &|_| (0..choice.len()).collect(),
+        &|assigned, index| {
+            !assigned.contains(&index) && choice[index].contains(pick[assigned.len()])
+        }
+    ).is_some()
+}
+
+// Keep picks not known yet that can all be matched to a
+// choice, in the order of their options, adding them to
+// known ones, returning the number of choices left
+fn apply_choice<T: Eq + Hash + Clone>(used: &mut HashSet<T>, choice: &[Vec<T>],
+    known: &mut HashSet<T>) -> usize {
+    let mut pick: Vec<T> = used.drain()
+        .filter(|pick| !known.contains(pick))
+        .collect();
+    pick.sort_by_key(|pick| choice.iter().flatten().position(|option| option == pick));
+    for pick in pick {
+        if fits_choice(choice, used.iter().chain([&pick])) {
+            used.insert(pick);
+        }
+    }
+    known.extend(used.iter().cloned());
+    choice.len() - used.len()
+}
+
+// Option lists of each tool choice
+fn tool_option(choice: &[ToolChoice]) -> Vec<Vec<Tools>> {
+    choice.iter().map(ToolChoice::get_option).collect()
+}
+
 impl Debug for Character<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let mut scores = self.get_all_ability_score();
@@ -782,12 +5281,21 @@ impl Debug for Character<'_> {
             STR DEX CON INT WIS CHA\n\
             [{}] [{}] [{}] [{}] [{}] [{}]\n\
             Class: \t{:?}\n\
+            Level: \t{} ({} XP)\n\
+            HP: \t{}/{} (+{})\n\
             Race: \t{}\n\
+            Background: {}\n\
             Languages: {:?}\n\
             Weapons:   {:?}\n\
             Armor: \t{:?}\n\
             Skills: {:?}\n\
+            Tools: \t{:?}\n\
+            Saves: \t{:?}\n\
+            ASI: \t{:?}\n\
+            Feats: \t{:?}\n\
             Speed: \t{:?}\n\
+            Weight: {}/{} lb\n\
+            AC: \t{}\n\
             Size: \t{:?}\n\
             AP: \t{}\n\
             LP: \t{}\n\
@@ -797,13 +5305,22 @@ impl Debug for Character<'_> {
             score.next().unwrap(), score.next().unwrap(),
             score.next().unwrap(), score.next().unwrap(),
             score.next().unwrap(), score.next().unwrap(),
-            self.class,
+            self.get_all_class(),
+            self.get_level(), self.xp,
+            self.get_current_hp(), self.get_max_hp(), self.hp_temp,
             self.race.as_string(),
+            self.background.as_string(),
             self.lang,
             self.weap,
             self.armor,
             self.skill,
-            self.speed,
+            self.tools,
+            self.saving_throw,
+            self.asi,
+            self.feat,
+            self.get_speed(),
+            self.get_carried_weight(), self.get_carrying_capacity(),
+            self.get_armor_class(),
             self.size,
             self.race_usable_ap,
             self.lang_point,
@@ -1,18 +1,26 @@
-use crate::common::{Stat, Race, Size, first_letter_uppercase};
-use crate::common::profeciency::{Language::*, Weapon::*, Skill::*, Armor};
+use crate::common::{AP, Race, Size, first_letter_uppercase};
+use super::traits::RacialTrait::{self, *};
+use super::equipment::WeaponProf::{self, Single};
+use super::tool::ToolChoice;
+use crate::common::profeciency::{
+    Language::{self, *}, Weapon::{self, *}, Skill::{self, *}, Armor, Tools
+};
 
 /* ---------
    | Macro |
    --------- */
 macro_rules! new_race {
     ($name:ident, $($sub_name:ident{ap: $ap:expr,
-        lang_point: $lang_point:expr, 
-        lang: $lang:expr, 
+        lang: $lang:expr,
         weap: $weap:expr,
         armor: $armor:expr,
+        tools: $tools:expr,
         skill: $skill:expr,
+        choice: $choice:expr,
+        feat_point: $feat_point:expr,
         speed: $speed:expr,
-        size: $size:expr})*) => {
+        size: $size:expr,
+        traits: $traits:expr})*) => {
         pub enum $name {
             $(
                 $sub_name,
@@ -20,6 +28,8 @@ macro_rules! new_race {
         }
 
         impl Race for $name {
+            type Stat = Stat;
+
             fn as_string(&self) -> String {
                 match self {
                     $(
@@ -39,13 +49,16 @@ macro_rules! new_race {
                     $(
                         Self::$sub_name => Stat {
                         ap: $ap, 
-                        lang_point: $lang_point,
                         lang: $lang,
                         weap: $weap,
                         armor: $armor,
+                        tools: $tools,
                         skill: $skill,
+                        choice: $choice,
+                        feat_point: $feat_point,
                         speed: $speed,
                         size: $size,
+                        traits: $traits,
                         },
                     )*
                 }
@@ -54,101 +67,558 @@ macro_rules! new_race {
     }
 }
 
+/* ----------
+   | Struct |
+   ---------- */
+/// Everything a race grants
+#[derive(PartialEq, Eq, Debug)]
+pub struct Stat {
+    pub ap: [isize; 6],
+    pub lang: Vec<Language>,
+    pub weap: Vec<WeaponProf>,
+    pub armor: Vec<Armor>,
+    pub tools: Vec<Tools>,
+    pub skill: Vec<Skill>,
+    pub choice: Vec<RaceChoice>,
+    pub feat_point: usize,
+    pub speed: usize,
+    pub size: Size,
+    pub traits: Vec<RacialTrait>
+}
+
+/// Racial ability bonus to be assigned by the player, adding
+/// `amount` to `count` different abilities
+///
+/// Example:
+/// ```
+/// use role4me_lib::ed_5;
+/// use role4me_lib::prelude::*;
+///
+/// // Half-Elf: +1 to two abilities other than CHA
+/// let grant = ed_5::AbilityGrant::new(1, 2).except(&[AP::CHA]);
+///
+/// assert!(grant.allows(AP::STR));
+/// assert!(!grant.allows(AP::CHA));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct AbilityGrant {
+    pub amount: isize,
+    pub count: usize,
+    /// Abilities the bonus is limited to, any when empty
+    pub allowed: Vec<AP>,
+    pub forbidden: Vec<AP>,
+    /// Whether the bonus can go on an ability receiving
+    /// another stacking bonus of choice from race
+    pub stack: bool
+}
+
+impl AbilityGrant {
+    /// Grant `amount` to `count` abilities of choice
+    pub fn new(amount: isize, count: usize) -> AbilityGrant {
+        AbilityGrant {
+            amount,
+            count,
+            allowed: Vec::new(),
+            forbidden: Vec::new(),
+            stack: false
+        }
+    }
+
+    /// Limit the bonus to the listed abilities
+    pub fn only(mut self, ability: &[AP]) -> Self {
+        self.allowed = ability.to_vec();
+        self
+    }
+
+    /// Forbid the bonus on the listed abilities
+    pub fn except(mut self, ability: &[AP]) -> Self {
+        self.forbidden = ability.to_vec();
+        self
+    }
+
+    /// Allow the bonus to stack with other stacking bonuses
+    pub fn stacking(mut self) -> Self {
+        self.stack = true;
+        self
+    }
+
+    /// Return whether the bonus can be put on an ability
+    pub fn allows(&self, ability: AP) -> bool {
+        (self.allowed.is_empty() || self.allowed.contains(&ability))
+            && !self.forbidden.contains(&ability)
+    }
+}
+
+/* --------
+   | Enum |
+   -------- */
+/// Choice granted by a race, one pick per choice except
+/// ability bonuses which are picked `count` times
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum RaceChoice {
+    /// Ability bonus to assign
+    Ability(AbilityGrant),
+    /// Any language
+    Language,
+    /// One of the listed skills
+    Skill(Vec<Skill>),
+    /// One tool of a tool choice
+    Tool(ToolChoice),
+    /// One of the listed weapons
+    Weapon(Vec<Weapon>),
+    /// One of the listed racial traits, such as an ancestry
+    Trait(Vec<RacialTrait>)
+}
+
+impl RaceChoice {
+    /// Return ability bonus to assign,
+    /// None for other kinds of choice
+    pub fn get_ability(&self) -> Option<AbilityGrant> {
+        match self {
+            Self::Ability(grant) => Some(grant.clone()),
+            _ => None
+        }
+    }
+
+    /// Return languages that can be picked,
+    /// None for other kinds of choice
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let choice = ed_5::RaceChoice::Language;
+    ///
+    /// assert_eq!(choice.get_language(), Some(Language::get_all()));
+    /// assert_eq!(choice.get_skill(), None);
+    /// ```
+    pub fn get_language(&self) -> Option<Vec<Language>> {
+        match self {
+            Self::Language => Some(Language::get_all()),
+            _ => None
+        }
+    }
+
+    /// Return skills that can be picked,
+    /// None for other kinds of choice
+    pub fn get_skill(&self) -> Option<Vec<Skill>> {
+        match self {
+            Self::Skill(skill) => Some(skill.clone()),
+            _ => None
+        }
+    }
+
+    /// Return tools that can be picked,
+    /// None for other kinds of choice
+    pub fn get_tools(&self) -> Option<Vec<Tools>> {
+        match self {
+            Self::Tool(choice) => Some(choice.get_option()),
+            _ => None
+        }
+    }
+
+    /// Return weapons that can be picked,
+    /// None for other kinds of choice
+    pub fn get_weapon(&self) -> Option<Vec<Weapon>> {
+        match self {
+            Self::Weapon(weapon) => Some(weapon.clone()),
+            _ => None
+        }
+    }
+
+    /// Return racial traits that can be picked,
+    /// None for other kinds of choice
+    pub fn get_trait(&self) -> Option<Vec<RacialTrait>> {
+        match self {
+            Self::Trait(racial_trait) => Some(racial_trait.clone()),
+            _ => None
+        }
+    }
+}
+
 /* ---------
    | Races |
    --------- */
 // Human
 new_race!(Human,
     Basic {
-        ap: [1,1,1,1,1,1,0],
-        lang_point: 1,
+        ap: [1,1,1,1,1,1],
         lang: vec![Common],
         weap: vec![],
         armor: vec![],
+        tools: vec![],
         skill: vec![],
+        choice: vec![RaceChoice::Language],
+        feat_point: 0,
         speed: 30,
-        size: Size::Medium
+        size: Size::Medium,
+        traits: vec![]
     }
     Variant {
-        ap: [0,0,0,0,0,0,2],
-        lang_point: 1,
+        ap: [0,0,0,0,0,0],
         lang: vec![Common],
         weap: vec![],
         armor: vec![],
+        tools: vec![],
         skill: vec![],
+        choice: vec![
+            RaceChoice::Ability(AbilityGrant::new(1, 2)),
+            RaceChoice::Language,
+            RaceChoice::Skill(Skill::get_all())
+        ],
+        feat_point: 1,
         speed: 30,
-        size: Size::Medium
+        size: Size::Medium,
+        traits: vec![]
     }
 );
 
 // Elf
 new_race!(Elf,
     Drow {
-        ap: [0,2,0,0,0,1,0],
-        lang_point: 0,
+        ap: [0,2,0,0,0,1],
         lang: vec![Common, Elven],
-        weap: vec![Rapier, Shortsword, HandCrossbow],
+        weap: vec![
+            Single(Rapier), Single(Shortsword),
+            Single(HandCrossbow)
+        ],
         armor: vec![],
+        tools: vec![],
         skill: vec![Perception],
+        choice: vec![],
+        feat_point: 0,
         speed: 30,
-        size: Size::Medium
+        size: Size::Medium,
+        traits: vec![
+            SuperiorDarkvision, KeenSenses, FeyAncestry,
+            Trance, SunlightSensitivity, DrowMagic
+        ]
     }
     High {
-        ap: [0,2,0,1,0,0,0],
-        lang_point: 1,
+        ap: [0,2,0,1,0,0],
         lang: vec![Common, Elven],
-        weap: vec![Longsword, Shortsword, Shortbow, Longbow],
+        weap: vec![
+            Single(Longsword), Single(Shortsword),
+            Single(Shortbow), Single(Longbow)
+        ],
         armor: vec![],
+        tools: vec![],
         skill: vec![Perception],
+        choice: vec![RaceChoice::Language],
+        feat_point: 0,
         speed: 30,
-        size: Size::Medium
+        size: Size::Medium,
+        traits: vec![
+            Darkvision, KeenSenses, FeyAncestry, Trance,
+            ElfWeaponTraining, Cantrip
+        ]
     }
     Wood {
-        ap: [0,2,0,0,1,0,0],
-        lang_point: 0,
+        ap: [0,2,0,0,1,0],
         lang: vec![Common, Elven],
-        weap: vec![Longsword, Shortsword, Shortbow, Longbow],
+        weap: vec![
+            Single(Longsword), Single(Shortsword),
+            Single(Shortbow), Single(Longbow)
+        ],
         armor: vec![],
+        tools: vec![],
         skill: vec![Perception],
+        choice: vec![],
+        feat_point: 0,
         speed: 35,
-        size: Size::Medium
+        size: Size::Medium,
+        traits: vec![
+            Darkvision, KeenSenses, FeyAncestry, Trance,
+            ElfWeaponTraining, FleetOfFoot, MaskOfTheWild
+        ]
     }
     Sea {
-        ap: [0,2,1,0,0,0,0],
-        lang_point: 0,
+        ap: [0,2,1,0,0,0],
         lang: vec![Common, Elven, Aquan],
-        weap: vec![Spear, Trident, LightCrossbow, Net],
+        weap: vec![
+            Single(Spear), Single(Trident),
+            Single(LightCrossbow), Single(Net)
+        ],
         armor: vec![],
+        tools: vec![],
         skill: vec![Perception],
+        choice: vec![],
+        feat_point: 0,
         speed: 30,
-        size: Size::Medium
+        size: Size::Medium,
+        traits: vec![
+            Darkvision, KeenSenses, FeyAncestry, Trance,
+            ChildOfTheSea, FriendOfTheSea
+        ]
     }
 );
 
 //Dwarf
 new_race!(Dwarf,
     Duegar {
-        ap: [1,0,2,0,0,0,0],
-        lang_point: 0,
+        ap: [1,0,2,0,0,0],
         lang: vec![Common, Dwarven, Undercommon],
-        weap: vec![Battleaxe, Handaxe, LightHammer, Warhammer],
+        weap: vec![
+            Single(Battleaxe), Single(Handaxe),
+            Single(LightHammer), Single(Warhammer)
+        ],
         armor: vec![Armor::Light, Armor::Medium],
+        tools: vec![],
         skill: vec![],
+        choice: vec![
+            RaceChoice::Tool(ToolChoice::Among(vec![
+                Tools::SmithsTools, Tools::BrewersSupplies, Tools::MasonsTools
+            ]))
+        ],
+        feat_point: 0,
         speed: 25,
-        size: Size::Medium
+        size: Size::Medium,
+        traits: vec![
+            SuperiorDarkvision, DwarvenResilience,
+            DwarvenSpeed, DwarvenCombatTraining, Stonecunning,
+            DuergarResilience, DuergarMagic,
+            SunlightSensitivity
+        ]
+    }
+    Hill {
+        ap: [0,0,2,0,1,0],
+        lang: vec![Common, Dwarven],
+        weap: vec![
+            Single(Battleaxe), Single(Handaxe),
+            Single(LightHammer), Single(Warhammer)
+        ],
+        armor: vec![],
+        tools: vec![],
+        skill: vec![],
+        choice: vec![
+            RaceChoice::Tool(ToolChoice::Among(vec![
+                Tools::SmithsTools, Tools::BrewersSupplies, Tools::MasonsTools
+            ]))
+        ],
+        feat_point: 0,
+        speed: 25,
+        size: Size::Medium,
+        traits: vec![
+            Darkvision, DwarvenResilience,
+            DwarvenSpeed, DwarvenCombatTraining, Stonecunning,
+            DwarvenToughness
+        ]
+    }
+    Mountain {
+        ap: [2,0,2,0,0,0],
+        lang: vec![Common, Dwarven],
+        weap: vec![
+            Single(Battleaxe), Single(Handaxe),
+            Single(LightHammer), Single(Warhammer)
+        ],
+        armor: vec![Armor::Light, Armor::Medium],
+        tools: vec![],
+        skill: vec![],
+        choice: vec![
+            RaceChoice::Tool(ToolChoice::Among(vec![
+                Tools::SmithsTools, Tools::BrewersSupplies, Tools::MasonsTools
+            ]))
+        ],
+        feat_point: 0,
+        speed: 25,
+        size: Size::Medium,
+        traits: vec![
+            Darkvision, DwarvenResilience,
+            DwarvenSpeed, DwarvenCombatTraining, Stonecunning,
+            DwarvenArmorTraining
+        ]
+    }
+);
+
+// Halfling
+new_race!(Halfling,
+    Lightfoot {
+        ap: [0,2,0,0,0,1],
+        lang: vec![Common, Halfling],
+        weap: vec![],
+        armor: vec![],
+        tools: vec![],
+        skill: vec![],
+        choice: vec![],
+        feat_point: 0,
+        speed: 25,
+        size: Size::Small,
+        traits: vec![Lucky, Brave, HalflingNimbleness, NaturallyStealthy]
+    }
+    Stout {
+        ap: [0,2,1,0,0,0],
+        lang: vec![Common, Halfling],
+        weap: vec![],
+        armor: vec![],
+        tools: vec![],
+        skill: vec![],
+        choice: vec![],
+        feat_point: 0,
+        speed: 25,
+        size: Size::Small,
+        traits: vec![Lucky, Brave, HalflingNimbleness, StoutResilience]
+    }
+);
+
+// Gnome
+new_race!(Gnome,
+    Forest {
+        ap: [0,1,0,2,0,0],
+        lang: vec![Common, Gnomish],
+        weap: vec![],
+        armor: vec![],
+        tools: vec![],
+        skill: vec![],
+        choice: vec![],
+        feat_point: 0,
+        speed: 25,
+        size: Size::Small,
+        traits: vec![
+            Darkvision, GnomeCunning, NaturalIllusionist,
+            SpeakWithSmallBeasts
+        ]
+    }
+    Rock {
+        ap: [0,0,1,2,0,0],
+        lang: vec![Common, Gnomish],
+        weap: vec![],
+        armor: vec![],
+        tools: vec![Tools::TinkersTools],
+        skill: vec![],
+        choice: vec![],
+        feat_point: 0,
+        speed: 25,
+        size: Size::Small,
+        traits: vec![Darkvision, GnomeCunning, ArtificersLore, Tinker]
+    }
+);
+
+// Half-Elf
+new_race!(HalfElf,
+    Basic {
+        ap: [0,0,0,0,0,2],
+        lang: vec![Common, Elven],
+        weap: vec![],
+        armor: vec![],
+        tools: vec![],
+        skill: vec![],
+        choice: vec![
+            RaceChoice::Ability(AbilityGrant::new(1, 2).except(&[AP::CHA])),
+            RaceChoice::Language,
+            RaceChoice::Skill(Skill::get_all()),
+            RaceChoice::Skill(Skill::get_all())
+        ],
+        feat_point: 0,
+        speed: 30,
+        size: Size::Medium,
+        traits: vec![Darkvision, FeyAncestry, SkillVersatility]
+    }
+);
+
+// Half-Orc
+new_race!(HalfOrc,
+    Basic {
+        ap: [2,0,1,0,0,0],
+        lang: vec![Common, Orc],
+        weap: vec![],
+        armor: vec![],
+        tools: vec![],
+        skill: vec![Intimidation],
+        choice: vec![],
+        feat_point: 0,
+        speed: 30,
+        size: Size::Medium,
+        traits: vec![Darkvision, Menacing, RelentlessEndurance, SavageAttacks]
+    }
+);
+
+// Tiefling
+new_race!(Tiefling,
+    Basic {
+        ap: [0,0,0,1,0,2],
+        lang: vec![Common, Infernal],
+        weap: vec![],
+        armor: vec![],
+        tools: vec![],
+        skill: vec![],
+        choice: vec![],
+        feat_point: 0,
+        speed: 30,
+        size: Size::Medium,
+        traits: vec![Darkvision, HellishResistance, InfernalLegacy]
+    }
+);
+
+// Dragonborn
+new_race!(Dragonborn,
+    Basic {
+        ap: [2,0,0,0,0,1],
+        lang: vec![Common, Draconic],
+        weap: vec![],
+        armor: vec![],
+        tools: vec![],
+        skill: vec![],
+        choice: vec![RaceChoice::Trait(vec![
+            DraconicAncestryBlack, DraconicAncestryBlue, DraconicAncestryBrass,
+            DraconicAncestryBronze, DraconicAncestryCopper, DraconicAncestryGold,
+            DraconicAncestryGreen, DraconicAncestryRed, DraconicAncestrySilver,
+            DraconicAncestryWhite
+        ])],
+        feat_point: 0,
+        speed: 30,
+        size: Size::Medium,
+        traits: vec![]
+    }
+);
+
+// Kobold (legacy)
+new_race!(Kobold,
+    Basic {
+        ap: [-2,2,0,0,0,0],
+        lang: vec![Common, Draconic],
+        weap: vec![],
+        armor: vec![],
+        tools: vec![],
+        skill: vec![],
+        choice: vec![],
+        feat_point: 0,
+        speed: 30,
+        size: Size::Small,
+        traits: vec![Darkvision, GrovelCowerAndBeg, PackTactics, SunlightSensitivity]
+    }
+);
+
+// Orc (legacy)
+new_race!(Orc,
+    Basic {
+        ap: [2,0,1,-2,0,0],
+        lang: vec![Common, Language::Orc],
+        weap: vec![],
+        armor: vec![],
+        tools: vec![],
+        skill: vec![Intimidation],
+        choice: vec![],
+        feat_point: 0,
+        speed: 30,
+        size: Size::Medium,
+        traits: vec![Darkvision, Aggressive, Menacing, PowerfulBuild]
     }
 );
 
 // No Race
 new_race!(Unknown,
     Unknown {
-        ap: [0,0,0,0,0,0,0],
-        lang_point: 0,
+        ap: [0,0,0,0,0,0],
         lang: vec![],
         weap: vec![],
         armor: vec![],
+        tools: vec![],
         skill: vec![],
+        choice: vec![],
+        feat_point: 0,
         speed: 0,
-        size: Size::Unknown
+        size: Size::Unknown,
+        traits: vec![]
     }
 );
@@ -1,5 +1,13 @@
 #[cfg(test)]
 use super::*;
+#[cfg(test)]
+use background::CustomBackground;
+#[cfg(test)]
+use feat::{Feat, FeatPrerequisite};
+#[cfg(test)]
+use equipment::{ArmorItem, ArmorPenalty, Encumbrance, EncumbranceRule, Gear, Item};
+#[cfg(test)]
+use tool::ToolCategory;
 
 #[test]
 fn test_new_race() {
@@ -9,7 +17,7 @@ fn test_new_race() {
 
      assert_eq!(player.get_all_ability_score(), [0,2,0,1,0,0]);
      assert_eq!(player.race_usable_ap, 0);
-     assert_eq!(player.race_used_ability, HashSet::new());
+     assert_eq!(player.race_used_ability, Vec::new());
      assert_eq!(player.get_all_lang(), &HashSet::from([
              Language::Elven,
              Language::Common
@@ -42,7 +50,7 @@ fn test_change_race() {
 
     assert_eq!(player.get_all_ability_score(), [0,0,0,0,0,0]);
     assert_eq!(player.race_usable_ap, 0);
-    assert_eq!(player.race_used_ability, HashSet::new());
+    assert_eq!(player.race_used_ability, Vec::new());
     assert_eq!(player.get_all_lang().len(), 0);
     assert_eq!(player.lang_point, 0);
     assert_eq!(player.weap, HashSet::new());
@@ -64,7 +72,7 @@ fn test_use_ap() {
 
     assert_eq!(player.get_all_ability_score(), [0,0,0,0,0,0]);
     assert_eq!(player.race_usable_ap, 0);
-    assert_eq!(player.race_used_ability, HashSet::new());
+    assert_eq!(player.race_used_ability, Vec::new());
 
     // Test race with no usable AP
     player
@@ -99,7 +107,7 @@ fn test_remove_ap() {
         .race_remove_ap(AP::STR);
 
     assert_eq!(player.get_all_ability_score(), [0,0,0,0,0,0]);
-    assert_eq!(player.race_used_ability, HashSet::new());
+    assert_eq!(player.race_used_ability, Vec::new());
 
     // Assert point used on STR
     player
@@ -107,14 +115,14 @@ fn test_remove_ap() {
         .race_use_ap(AP::STR);
 
     assert_eq!(player.get_all_ability_score(), [1,0,0,0,0,0]);
-    assert_eq!(player.race_used_ability, HashSet::from([AP::STR]));
+    assert_eq!(player.race_used_ability, vec![(AP::STR, 0)]);
 
     // Test point removed
     player
         .race_remove_ap(AP::STR);
 
     assert_eq!(player.get_all_ability_score(), [0,0,0,0,0,0]);
-    assert_eq!(player.race_used_ability, HashSet::new());
+    assert_eq!(player.race_used_ability, Vec::new());
 }
 
 #[test]
@@ -131,7 +139,7 @@ fn test_no_race() {
         .race_clear_ap();
 
     assert_eq!(player.get_all_ability_score(), [0,0,0,0,0,0]);
-    assert_eq!(player.race_used_ability, HashSet::new());
+    assert_eq!(player.race_used_ability, Vec::new());
     assert_eq!(player.get_all_lang(), &HashSet::new());
 }
 
@@ -155,7 +163,1266 @@ fn test_ap_sequence() {
     // Ensure that changing ap assign method reset ap_seq
     player.ap_standard_array();
 
-    if let Ok(_) = player.get_ap_seq() {
-        panic!("Error: Expected Err, got Ok(_)");
+    assert_eq!(player.get_ap_seq(), Err(CharacterError::NoSequence));
+}
+
+#[test]
+fn test_ap_sequence_error() {
+    let mut player = Character::build();
+
+    // Sequence order is applied to unassigned stat
+    player
+        .ap_standard_array()
+        .ap_assign_seq([5,4,3,2,1,0]);
+
+    assert_eq!(player.get_ap_seq(), Ok([5,4,3,2,1,0]));
+    assert_eq!(player.get_all_ability_score(), [8,10,12,13,14,15]);
+
+    // Rolled value i goes to ability sequence[i]
+    player.ap_assign_seq([2,1,3,5,4,0]);
+
+    assert_eq!(player.get_all_ability_score(), [8,14,15,13,10,12]);
+
+    // Duplicate index keeps the previous sequence
+    assert_eq!(
+        player.try_ap_assign_seq([0,0,1,2,3,4]).unwrap_err(),
+        CharacterError::DuplicateSequence { sequence: [0,0,1,2,3,4], index: 0 }
+    );
+    assert_eq!(player.get_ap_seq(), Ok([2,1,3,5,4,0]));
+    assert_eq!(player.get_all_ability_score(), [8,14,15,13,10,12]);
+
+    // Index out of range
+    player.ap_assign_seq([0,1,2,3,4,6]);
+
+    assert_eq!(
+        player.try_ap_assign_seq([0,1,2,3,4,6]).unwrap_err(),
+        CharacterError::SequenceOutOfRange { sequence: [0,1,2,3,4,6], index: 6 }
+    );
+    assert_eq!(player.get_ap_seq(), Ok([2,1,3,5,4,0]));
+}
+
+#[test]
+fn test_check_point_buy() {
+    let player = Character::build();
+
+    assert_eq!(player.ap_check_point_buy([8,8,8,8,8,8]), Ok(27));
+    assert_eq!(player.ap_check_point_buy([7,8,8,8,8,8]),
+        Err(CharacterError::PointOutOfRange {
+            points: [7,8,8,8,8,8], value: 7
+        }));
+    assert_eq!(player.ap_check_point_buy([15,15,15,15,15,15]),
+        Err(CharacterError::PointBuyExceeded {
+            limit: 27, assigned: 54
+        }));
+}
+
+#[test]
+fn test_try_race_choice() {
+    let mut player = Character::build();
+
+    // No race selected
+    assert_eq!(player.try_race_use_ap(AP::STR).unwrap_err(),
+        CharacterError::NoRaceAbilityPoint);
+    assert_eq!(player.try_race_use_lang(Language::Elven).unwrap_err(),
+        CharacterError::NoLanguagePoint);
+
+    player.race_select(Human::Variant);
+
+    assert!(player.try_race_use_ap(AP::STR).is_ok());
+    assert_eq!(player.try_race_use_ap(AP::STR).unwrap_err(),
+        CharacterError::AbilityAlreadyAssigned(AP::STR));
+    assert!(player.try_race_use_ap(AP::DEX).is_ok());
+    assert_eq!(player.try_race_use_ap(AP::CON).unwrap_err(),
+        CharacterError::NoRaceAbilityPoint);
+    assert_eq!(player.try_race_remove_ap(AP::CON).unwrap_err(),
+        CharacterError::AbilityNotAssigned(AP::CON));
+    assert_eq!(player.get_all_ability_score(), [1,1,0,0,0,0]);
+
+    assert!(player.try_race_use_lang(Language::Elven).is_ok());
+    assert_eq!(player.try_race_remove_lang(Language::Dwarven).unwrap_err(),
+        CharacterError::LanguageNotAssigned(Language::Dwarven));
+    assert!(player.try_race_remove_lang(Language::Elven).is_ok());
+    assert_eq!(player.lang_point, 1);
+}
+
+#[test]
+fn test_try_point_buy() {
+    let mut player = Character::build();
+
+    assert_eq!(player.try_ap_point_buy([15,15,15,15,8,8]).unwrap_err(),
+        CharacterError::PointBuyExceeded { limit: 27, assigned: 36 });
+    assert_eq!(player.get_all_ability_score(), [0,0,0,0,0,0]);
+
+    assert!(player.try_ap_point_buy([15,15,15,8,8,8]).is_ok());
+    assert_eq!(player.get_all_ability_score(), [15,15,15,8,8,8]);
+}
+
+#[test]
+fn test_derived_stat() {
+    let mut player = Character::build();
+
+    player.ap_point_buy([8,9,10,11,15,15]);
+
+    assert_eq!(player.get_all_ability_modifier(), [-1,-1,0,0,2,2]);
+    assert_eq!(Character::proficiency_bonus(1), 2);
+    assert_eq!(Character::proficiency_bonus(5), 3);
+    assert_eq!(Character::proficiency_bonus(20), 6);
+    assert_eq!(player.get_passive_perception(), 12);
+
+    // Derived stat follows race change
+    player.race_select(Elf::Wood);
+
+    assert_eq!(player.get_initiative(), 0);
+    assert_eq!(player.get_passive_perception(), 15);
+    assert_eq!(player.get_carrying_capacity(), 120);
+
+    player.race_select(Unknown::Unknown);
+
+    assert_eq!(player.get_passive_perception(), 12);
+}
+
+#[test]
+fn test_skill_bonus() {
+    let mut player = Character::build();
+
+    assert_eq!(Skill::Stealth.get_ability(), AP::DEX);
+    assert_eq!(Skill::Survial.get_ability(), AP::WIS);
+
+    player
+        .race_select(Elf::Drow)
+        .ap_point_buy([8,14,14,15,12,8])
+        .skill_use_expertise(Skill::Perception)
+        .skill_use_expertise(Skill::Stealth);
+
+    assert_eq!(player.expertise, HashSet::from([Skill::Perception]));
+    assert_eq!(player.get_skill_bonus(Skill::Perception), 5);
+    assert_eq!(player.get_skill_bonus(Skill::Stealth), 3);
+    assert_eq!(player.get_passive_perception(), 15);
+
+    // Expertise is lost with proficiency
+    player.race_select(Human::Basic);
+
+    assert_eq!(player.expertise, HashSet::new());
+    assert_eq!(player.get_skill_bonus(Skill::Perception), 1);
+
+    // and doesn't come back when proficiency does
+    player.race_select(Elf::Drow);
+
+    assert_eq!(player.get_skill_bonus(Skill::Perception), 3);
+}
+
+#[test]
+fn test_class_prof() {
+    let mut player = Character::build();
+
+    player
+        .race_select(Dwarf::Duegar)
+        .class_select(Class::Cleric);
+
+    assert_eq!(player.armor, HashSet::from([
+            Armor::Light,
+            Armor::Medium,
+            Armor::Shield
+    ]));
+    assert!(player.weap.contains(&Weapon::Battleaxe));
+    assert!(player.weap.contains(&Weapon::Mace));
+    assert_eq!(player.saving_throw, HashSet::from([AP::WIS, AP::CHA]));
+
+    // Class proficiencies kept when changing race
+    player.race_select(Elf::High);
+
+    assert_eq!(player.armor, HashSet::from([
+            Armor::Light,
+            Armor::Medium,
+            Armor::Shield
+    ]));
+    assert!(!player.weap.contains(&Weapon::Battleaxe));
+    assert!(player.weap.contains(&Weapon::Longsword));
+
+    // Previous class proficiencies removed when changing class
+    player.class_select(Class::Unknown);
+
+    assert_eq!(player.armor, HashSet::new());
+    assert_eq!(player.weap, HashSet::from([
+             Weapon::Longsword,
+             Weapon::Shortsword,
+             Weapon::Shortbow,
+             Weapon::Longbow
+    ]));
+    assert_eq!(player.saving_throw, HashSet::new());
+}
+
+#[test]
+fn test_class_skill() {
+    let mut player = Character::build();
+
+    // No class selected
+    assert_eq!(player.try_class_use_skill(Skill::Arcana).unwrap_err(),
+        CharacterError::NoClassSkillPoint);
+
+    player.class_select(Class::Ranger);
+
+    assert_eq!(player.class[0].skill_point, 3);
+    assert!(player.try_class_use_skill(Skill::Stealth).is_ok());
+    assert_eq!(player.try_class_use_skill(Skill::Stealth).unwrap_err(),
+        CharacterError::SkillKnown(Skill::Stealth));
+    assert_eq!(player.try_class_use_skill(Skill::Arcana).unwrap_err(),
+        CharacterError::SkillNotAvailable(Skill::Arcana));
+
+    // Perception from race allows one skill outside of class list
+    player
+        .race_select(Elf::Wood)
+        .class_use_skill(Skill::Arcana)
+        .class_use_skill(Skill::History);
+
+    assert_eq!(player.class[0].used_skill, HashSet::from([
+            Skill::Stealth,
+            Skill::Arcana
+    ]));
+    assert_eq!(player.class[0].skill_point, 1);
+    assert_eq!(player.try_class_use_skill(Skill::Perception).unwrap_err(),
+        CharacterError::SkillKnown(Skill::Perception));
+
+    // Choice outside of class list dropped when no longer covered
+    player.race_select(Human::Basic);
+
+    assert_eq!(player.class[0].used_skill, HashSet::from([Skill::Stealth]));
+    assert_eq!(player.skill, HashSet::from([Skill::Stealth]));
+    assert_eq!(player.class[0].skill_point, 2);
+    assert_eq!(player.try_class_remove_skill(Skill::Arcana).unwrap_err(),
+        CharacterError::SkillNotAssigned(Skill::Arcana));
+
+    // Choices dropped when changing class
+    player.class_select(Class::Cleric);
+
+    assert_eq!(player.skill, HashSet::new());
+    assert_eq!(player.class[0].skill_point, 2);
+
+    // Only the choices no longer covered are dropped
+    player
+        .class_select(Class::Ranger)
+        .race_select(Elf::Wood)
+        .background_select(Background::Outlander)
+        .class_use_skill(Skill::Arcana)
+        .class_use_skill(Skill::History)
+        .class_use_skill(Skill::Religion)
+        .race_select(Human::Basic);
+
+    assert_eq!(player.class[0].used_skill, HashSet::from([
+            Skill::Arcana,
+            Skill::History
+    ]));
+    assert_eq!(player.class[0].skill_point, 1);
+}
+
+#[test]
+fn test_saving_throw() {
+    let mut player = Character::build();
+
+    player.ap_point_buy([10,10,10,10,10,10]);
+
+    assert_eq!(player.get_all_saving_throw_bonus(), [0,0,0,0,0,0]);
+
+    player.class_select(Class::Cleric);
+
+    assert_eq!(player.get_all_saving_throw_bonus(), [0,0,0,0,2,2]);
+
+    player.class_select(Class::Ranger);
+
+    assert_eq!(player.get_all_saving_throw_bonus(), [2,2,0,0,0,0]);
+}
+
+#[test]
+fn test_level() {
+    let mut player = Character::build();
+
+    assert_eq!(player.add_xp(299), 0);
+    assert_eq!(player.get_level(), 1);
+    assert_eq!(player.add_xp(1), 1);
+    assert_eq!(player.get_level(), 2);
+
+    // Milestone keeps experience points consistent
+    player.level_up();
+
+    assert_eq!(player.get_level(), 3);
+    assert_eq!(player.xp, 900);
+
+    // Experience points beyond threshold are kept
+    player.add_xp(2000);
+    player.level_up();
+
+    assert_eq!(player.get_level(), 5);
+    assert_eq!(player.xp, 6500);
+
+    // Capped at level 20
+    assert_eq!(player.add_xp(1000000), 15);
+    assert_eq!(player.get_level(), 20);
+    assert_eq!(player.get_proficiency_bonus(), 6);
+    assert_eq!(player.try_level_up().unwrap_err(), CharacterError::MaxLevel);
+
+    // Experience points saturate instead of overflowing
+    player.add_xp(usize::MAX);
+
+    assert_eq!(player.add_xp(1), 0);
+    assert_eq!(player.xp, usize::MAX);
+}
+
+#[test]
+fn test_hit_point() {
+    let mut player = Character::build();
+
+    // No class, no hit point
+    player.ap_point_buy([8,10,14,15,12,10]);
+
+    assert_eq!(player.get_max_hp(), 0);
+
+    player
+        .class_select(Class::Cleric)
+        .add_xp(900);
+
+    assert_eq!(player.class[0].hp_roll, vec![8,5,5]);
+    assert_eq!(player.get_max_hp(), 24);
+
+    // Change of class recalculates every level
+    player
+        .hp_method_select(HitPointMethod::Roll)
+        .class_select(Class::Ranger);
+
+    assert_eq!(player.class[0].hp_roll.len(), 3);
+    assert_eq!(player.class[0].hp_roll[0], 10);
+    assert!(player.class[0].hp_roll.iter().all(|roll| (1..=10).contains(roll)));
+
+    // Damage and temporary hit points
+    let max_hp = player.get_max_hp();
+    player
+        .hp_gain_temp(5)
+        .hp_take_damage(7);
+
+    assert_eq!(player.hp_temp, 0);
+    assert_eq!(player.get_current_hp(), max_hp - 2);
+
+    player
+        .hp_take_damage(100)
+        .hp_take_damage(usize::MAX);
+
+    assert_eq!(player.get_current_hp(), 0);
+
+    player.hp_heal(100);
+
+    assert_eq!(player.get_current_hp(), max_hp);
+}
+
+#[test]
+fn test_multiclass() {
+    let mut player = Character::build();
+
+    // Unknown class cannot multiclass
+    player.ap_point_buy([13,14,13,8,13,10]);
+
+    assert_eq!(player.try_class_level_up(Class::Cleric).unwrap_err(),
+        CharacterError::MulticlassUnknown);
+
+    // Requirement of current class must be met
+    player
+        .ap_point_buy([13,14,13,8,12,12])
+        .class_select(Class::Cleric);
+
+    assert_eq!(player.try_class_level_up(Class::Ranger).unwrap_err(),
+        CharacterError::MulticlassRequirement {
+            class: Class::Cleric, ability: AP::WIS, required: 13
+        });
+
+    player
+        .race_select(Elf::Wood)
+        .class_level_up(Class::Ranger)
+        .level_up();
+
+    assert_eq!(player.get_all_class(), vec![
+        (Class::Cleric, 2),
+        (Class::Ranger, 1)
+    ]);
+    assert_eq!(player.get_level(), 3);
+    assert_eq!(player.xp, 900);
+
+    // Partial proficiencies from Ranger, saving throws from Cleric only
+    assert!(player.armor.contains(&Armor::Medium));
+    assert!(player.weap.contains(&Weapon::Longsword));
+    assert_eq!(player.saving_throw, HashSet::from([AP::WIS, AP::CHA]));
+    assert_eq!(player.class[1].skill_point, 1);
+
+    // Hit point maximum only at first character level
+    assert_eq!(player.class[0].hp_roll, vec![8,5]);
+    assert_eq!(player.class[1].hp_roll, vec![6]);
+
+    // Multiclass skill choice, Perception from race allows any skill
+    assert_eq!(player.try_multiclass_use_skill(Class::Unknown, Skill::Arcana)
+        .unwrap_err(), CharacterError::ClassNotTaken(Class::Unknown));
+    player.multiclass_use_skill(Class::Ranger, Skill::Arcana);
+
+    assert!(player.skill.contains(&Skill::Arcana));
+
+    // Selecting a multiclassed class merges levels
+    player.class_select(Class::Ranger);
+
+    assert_eq!(player.get_all_class(), vec![(Class::Ranger, 3)]);
+    assert_eq!(player.saving_throw, HashSet::from([AP::STR, AP::DEX]));
+    assert!(!player.skill.contains(&Skill::Arcana));
+
+    // Perception from race only counts for the first class listing it
+    player.class_level_up(Class::Rogue);
+
+    assert_eq!(player.try_multiclass_use_skill(Class::Rogue, Skill::Religion)
+        .unwrap_err(), CharacterError::SkillNotAvailable(Skill::Religion));
+
+    // Unknown class drops multiclassed classes
+    player
+        .multiclass_use_skill(Class::Rogue, Skill::Stealth)
+        .class_select(Class::Unknown);
+
+    assert_eq!(player.class.len(), 1);
+    assert!(player.armor.is_empty());
+    assert!(player.tools.is_empty());
+    assert!(!player.skill.contains(&Skill::Stealth));
+}
+
+#[test]
+fn test_race_catalogue() {
+    let mut player = Character::build();
+
+    player.race_select(Dwarf::Mountain);
+
+    assert_eq!(player.get_all_ability_score(), [2,0,2,0,0,0]);
+    assert_eq!(player.armor, HashSet::from([Armor::Light, Armor::Medium]));
+    assert_eq!(player.speed, 25);
+
+    player.race_select(Halfling::Lightfoot);
+
+    assert_eq!(player.get_all_ability_score(), [0,2,0,0,0,1]);
+    assert_eq!(player.lang, HashSet::from([
+            Language::Common,
+            Language::Halfling
+    ]));
+    assert_eq!(player.size, Size::Small);
+
+    player.race_select(HalfElf::Basic);
+
+    assert_eq!(player.get_all_ability_score(), [0,0,0,0,0,2]);
+    assert_eq!(player.race_usable_ap, 2);
+    assert_eq!(player.lang_point, 1);
+
+    player.race_select(HalfOrc::Basic);
+
+    assert_eq!(player.skill, HashSet::from([Skill::Intimidation]));
+
+    player.race_select(Dragonborn::Basic);
+
+    assert_eq!(player.get_race(), "Dragonborn(Basic)".to_string());
+    assert_eq!(player.get_all_ability_score(), [2,0,0,0,0,1]);
+    assert!(player.lang.contains(&Language::Draconic));
+}
+
+#[test]
+fn test_class_catalogue() {
+    let mut player = Character::build();
+
+    player.class_select(Class::Rogue);
+
+    assert_eq!(player.class[0].skill_point, 4);
+    assert_eq!(player.tools, HashSet::from([Tools::TheivesTools]));
+    assert_eq!(player.saving_throw, HashSet::from([AP::DEX, AP::INT]));
+    assert!(player.weap.contains(&Weapon::Rapier));
+    assert!(!player.weap.contains(&Weapon::Greatsword));
+
+    // Bard chooses any three skills
+    player
+        .class_select(Class::Bard)
+        .class_use_skill(Skill::Arcana)
+        .class_use_skill(Skill::Athletics)
+        .class_use_skill(Skill::Stealth);
+
+    assert_eq!(player.skill.len(), 3);
+    assert_eq!(player.class[0].skill_point, 0);
+
+    // Fighter multiclass requires STR or DEX
+    player
+        .ap_point_buy([8,13,10,10,10,15])
+        .class_level_up(Class::Fighter);
+
+    assert_eq!(player.get_all_class(), vec![
+        (Class::Bard, 1),
+        (Class::Fighter, 1)
+    ]);
+    assert!(player.armor.contains(&Armor::Medium));
+    assert!(!player.armor.contains(&Armor::Heavy));
+
+    player.class_select(Class::Barbarian);
+
+    assert_eq!(player.class[0].hp_roll, vec![12]);
+    assert_eq!(player.class[1].hp_roll, vec![6]);
+}
+
+#[test]
+fn test_racial_trait() {
+    let mut player = Character::build();
+
+    assert!(player.get_all_traits().is_empty());
+    assert_eq!(player.get_darkvision(), 0);
+
+    player.race_select(Elf::High);
+
+    assert!(player.get_all_traits().contains(&RacialTrait::FeyAncestry));
+    assert_eq!(player.get_darkvision(), 60);
+    assert_eq!(player.get_all_condition_advantage(),
+        HashSet::from([Condition::Charmed]));
+
+    // Dwarven Toughness adds hit points every level
+    player
+        .race_select(Dwarf::Hill)
+        .ap_point_buy([10,10,10,10,10,10])
+        .class_select(Class::Cleric)
+        .add_xp(300);
+
+    assert_eq!(player.get_all_resistance(), HashSet::from([DamageType::Poison]));
+    // (8 + 1) + (5 + 1) + Dwarven Toughness
+    assert_eq!(player.get_max_hp(), 17);
+
+    // Innate spells follow level
+    player.race_select(Elf::Drow);
+
+    assert_eq!(player.get_innate_spell(), vec!["Dancing Lights"]);
+}
+
+#[test]
+fn test_background() {
+    let mut player = Character::build();
+
+    player
+        .class_select(Class::Rogue)
+        .class_use_skill(Skill::Stealth)
+        .background_select(Background::Criminal);
+
+    // Class choice now granted by background is refunded
+    assert!(player.class[0].used_skill.is_empty());
+    assert_eq!(player.get_class_unused_skill(), &4);
+    assert!(player.get_all_skill().contains(&Skill::Deception));
+    assert!(player.get_all_tools().contains(&Tools::TheivesTools));
+    // Duplicates with the class list allow skills outside of it
+    assert!(player.try_class_use_skill(Skill::Medicine).is_ok());
+
+    // Background language choice now known from race is refunded
+    player
+        .background_select(Background::Sage)
+        .background_use_lang(Language::Elven)
+        .background_use_lang(Language::Draconic);
+
+    assert_eq!(player.get_background_unused_lang(), &0);
+
+    player.race_select(Elf::High);
+
+    assert_eq!(player.background_used_lang, HashSet::from([Language::Draconic]));
+    assert_eq!(player.get_background_unused_lang(), &1);
+    assert!(player.get_all_lang().contains(&Language::Draconic));
+
+    // Custom background follows PHB rules
+    assert_eq!(
+        CustomBackground::build()
+            .skill(Skill::Arcana)
+            .tool(Tools::ForgeryKit)
+            .lang()
+            .finish()
+            .unwrap_err(),
+        CharacterError::CustomBackgroundSkill(1)
+    );
+    assert_eq!(
+        CustomBackground::build()
+            .skill(Skill::Arcana)
+            .skill(Skill::Nature)
+            .lang()
+            .finish()
+            .unwrap_err(),
+        CharacterError::CustomBackgroundProficiency(1)
+    );
+    assert_eq!(
+        CustomBackground::build()
+            .skill(Skill::Arcana)
+            .skill(Skill::Nature)
+            .tool(Tools::ForgeryKit)
+            .lang()
+            .finish()
+            .unwrap_err(),
+        CharacterError::CustomBackgroundFeature
+    );
+
+    let custom = CustomBackground::build()
+        .skill(Skill::Arcana)
+        .skill(Skill::Nature)
+        .tool(Tools::ForgeryKit)
+        .lang()
+        .feature(Background::Hermit)
+        .finish()
+        .unwrap();
+    player.background_select(custom);
+
+    assert!(player.get_all_tools().contains(&Tools::ForgeryKit));
+    assert!(player.get_all_skill().contains(&Skill::Nature));
+    assert!(!player.get_all_skill().contains(&Skill::Deception));
+    assert_eq!(player.get_background_unused_lang(), &1);
+    assert_eq!(player.get_background_feature().name, "Discovery");
+    assert!(player.get_background_equipment().contains(&(Item::Tool(Tools::HerbalismKit), 1)));
+    assert!(player.get_background_equipment().contains(&(Item::Gear(Gear::GoldPiece), 5)));
+}
+
+#[test]
+fn test_feat() {
+    let mut player = Character::build();
+
+    player
+        .race_select(Human::Variant)
+        .ap_point_buy([15,14,13,12,10,8])
+        .class_select(Class::Fighter);
+
+    // Variant Human grants a feat and a skill
+    assert_eq!(player.get_feat_unused(), &1);
+    assert_eq!(player.get_race_unused_skill(), &1);
+
+    player
+        .race_use_skill(Skill::Perception)
+        .feat_use(Feat::Observant(AP::WIS));
+
+    assert_eq!(player.get_ability_score(AP::WIS), 11);
+    // 10 + WIS modifier (0) + proficiency bonus (2) + Observant (5)
+    assert_eq!(player.get_passive_perception(), 17);
+    assert_eq!(player.try_feat_use(Feat::Alert).unwrap_err(),
+        CharacterError::NoFeatPoint);
+
+    // Ability score improvement levels grant feat slots
+    player.add_xp(14000);
+
+    assert_eq!(player.get_feat_unused(), &2);
+    assert_eq!(player.try_feat_use(Feat::Observant(AP::INT)).unwrap_err(),
+        CharacterError::FeatKnown(Feat::Observant(AP::INT)));
+    assert!(player.try_feat_use(Feat::HeavilyArmored).is_ok());
+    assert!(player.try_feat_use(Feat::Tough).is_ok());
+    // (10 + 1) + 5 * (6 + 1) + Tough
+    assert_eq!(player.get_max_hp(), 58);
+
+    // War Caster needs spellcasting
+    assert_eq!(player.check_feat_prerequisite(&Feat::WarCaster),
+        Err(CharacterError::FeatRequirement {
+            feat: Feat::WarCaster,
+            prerequisite: FeatPrerequisite::Spellcasting
+        }));
+
+    // Losing the race feat slot drops the latest feat
+    player.race_select(Human::Basic);
+
+    assert_eq!(player.get_all_feat(), &[
+        Feat::Observant(AP::WIS),
+        Feat::HeavilyArmored
+    ]);
+    assert!(!player.get_all_skill().contains(&Skill::Perception));
+}
+
+#[test]
+fn test_ability_improvement() {
+    let mut player = Character::build();
+
+    player
+        .race_select(Dwarf::Mountain)
+        .ap_point_buy([15,14,13,12,10,8])
+        .class_select(Class::Fighter)
+        .add_xp(23000);
+
+    // Fighter levels 4 and 6
+    assert_eq!(player.get_asi_unused(), &2);
+
+    player.asi_use(AbilityImprovement::Single(AP::STR));
+
+    // +2 on 19 would go past 20
+    assert_eq!(player.try_asi_use(AbilityImprovement::Single(AP::STR)).unwrap_err(),
+        CharacterError::AbilityScoreMax(AP::STR));
+
+    player.asi_use(AbilityImprovement::Split(AP::STR, AP::CON));
+
+    // 15 + 2 (race) + 2 + 1 capped at 20
+    assert_eq!(player.get_ability_score(AP::STR), 20);
+    assert_eq!(player.try_feat_use(Feat::Alert).unwrap_err(),
+        CharacterError::NoFeatPoint);
+
+    // Choices are undone individually, racial points stay
+    player.asi_remove(AbilityImprovement::Single(AP::STR));
+
+    assert_eq!(player.get_ability_score(AP::STR), 18);
+    assert_eq!(player.get_ability_score(AP::CON), 16);
+    assert_eq!(player.get_all_asi(), &[AbilityImprovement::Split(AP::STR, AP::CON)]);
+
+    // A feat can be taken instead
+    player.feat_use(Feat::Alert);
+
+    assert_eq!(player.get_asi_unused(), &0);
+
+    // Losing slots drops the latest choices
+    player.class_select(Class::Barbarian);
+
+    assert_eq!(player.get_all_asi(), &[AbilityImprovement::Split(AP::STR, AP::CON)]);
+    assert!(player.get_all_feat().is_empty());
+}
+
+#[test]
+fn test_inventory() {
+    let mut player = Character::build();
+
+    player
+        .race_select(Dwarf::Hill)
+        .ap_point_buy([10,10,10,10,10,10])
+        .inventory_add(Item::Gear(Gear::Backpack), 1)
+        .inventory_add(Item::Gear(Gear::Rations), 10)
+        .inventory_add(Item::Weapon(Weapon::Battleaxe), 1);
+
+    assert_eq!(player.get_carried_weight(), 29.0);
+    assert_eq!(player.get_carrying_capacity(), 150);
+    assert_eq!(player.get_encumbrance(), Encumbrance::Unencumbered);
+
+    // Variant rule thresholds at 50 and 100 pounds
+    player
+        .encumbrance_rule_select(EncumbranceRule::Variant)
+        .inventory_add(Item::Armor(ArmorItem::Hide), 2);
+
+    assert_eq!(player.get_encumbrance(), Encumbrance::Encumbered);
+    assert_eq!(player.get_speed(), 15);
+
+    player.inventory_add(Item::Armor(ArmorItem::ChainMail), 1);
+
+    assert_eq!(player.get_encumbrance(), Encumbrance::HeavilyEncumbered);
+    assert_eq!(player.get_speed(), 5);
+
+    // Standard rule only checks carrying capacity
+    player.encumbrance_rule_select(EncumbranceRule::Standard);
+
+    assert_eq!(player.get_speed(), 25);
+
+    player
+        .inventory_remove(Item::Armor(ArmorItem::Hide), 2)
+        .inventory_remove(Item::Armor(ArmorItem::ChainMail), 1);
+
+    assert_eq!(player.get_carried_weight(), 29.0);
+    assert_eq!(
+        player.try_inventory_remove(Item::Gear(Gear::Rations), 11).unwrap_err(),
+        CharacterError::NotEnoughItem { item: Item::Gear(Gear::Rations), carried: 10 }
+    );
+
+    // Powerful Build carries as one size larger
+    player.race_select(Orc::Basic);
+
+    assert_eq!(player.get_carrying_capacity(), 360);
+    assert_eq!(player.get_push_drag_lift(), 720);
+}
+
+#[test]
+fn test_armor_class() {
+    let mut player = Character::build();
+
+    player
+        .race_select(Human::Basic)
+        .ap_point_buy([12,15,13,8,14,10])
+        .class_select(Class::Monk);
+
+    // Unarmored defense, 10 + DEX (3) + WIS (2)
+    assert_eq!(player.get_armor_class(), 15);
+
+    player
+        .inventory_add(Item::Armor(ArmorItem::Shield), 1)
+        .armor_equip(ArmorItem::Shield);
+
+    // Monk unarmored defense is lost with a shield
+    assert_eq!(player.get_armor_class(), 15);
+    assert_eq!(player.get_armor_penalty(), HashSet::from([ArmorPenalty::NotProficient]));
+
+    // Heavy armor without enough STR slows down, except dwarves
+    player
+        .inventory_add(Item::Armor(ArmorItem::Splint), 1)
+        .armor_equip(ArmorItem::Splint);
+
+    assert_eq!(player.get_armor_class(), 19);
+    assert_eq!(player.get_speed(), 20);
+
+    player.race_select(Dwarf::Hill);
+
+    assert_eq!(player.get_speed(), 25);
+
+    // Removing armor from inventory takes it off
+    player.inventory_remove(Item::Armor(ArmorItem::Splint), 1);
+
+    assert_eq!(player.get_equipped_armor(), None);
+    assert!(player.get_equipped_shield());
+
+    // Clearing inventory takes off everything
+    player
+        .inventory_add(Item::Armor(ArmorItem::Splint), 1)
+        .armor_equip(ArmorItem::Splint)
+        .inventory_clear();
+
+    assert_eq!(player.get_equipped_armor(), None);
+    assert!(!player.get_equipped_shield());
+    assert!(player.get_armor_penalty().is_empty());
+
+    // Heavy armor ignores a negative DEX modifier
+    let mut player = Character::build();
+
+    player
+        .ap_point_buy([15,8,14,10,12,13])
+        .class_select(Class::Fighter)
+        .inventory_add(Item::Armor(ArmorItem::Plate), 1)
+        .armor_equip(ArmorItem::Plate);
+
+    assert_eq!(player.get_armor_class(), 18);
+}
+
+#[test]
+fn test_weapon() {
+    let mut player = Character::build();
+
+    player
+        .ap_point_buy([12,15,12,10,10,8])
+        .class_select(Class::Rogue);
+
+    // Finesse picks DEX, heavy melee picks STR
+    assert_eq!(player.get_attack_bonus(Weapon::Rapier), 4);
+    assert_eq!(player.get_attack_bonus(Weapon::Greataxe), 1);
+    assert_eq!(player.get_attack_bonus(Weapon::HandCrossbow), 4);
+
+    assert_eq!(player.get_damage_expression(Weapon::Rapier), "1d8+2 Piercing");
+    assert_eq!(player.get_damage_expression(Weapon::Greataxe), "1d12+1 Slashing");
+    assert_eq!(player.get_damage_expression(Weapon::Net), "0");
+
+    // A zero modifier is left out, proficiency still applies
+    player.ap_point_buy([10,10,10,10,10,10]);
+
+    assert_eq!(player.get_damage_expression(Weapon::Javelin), "1d6 Piercing");
+    assert_eq!(player.get_attack_bonus(Weapon::Javelin), 2);
+}
+
+#[test]
+fn test_weapon_prof() {
+    let mut player = Character::build();
+
+    player
+        .ap_point_buy([10,10,10,10,10,10])
+        .race_select(Human::Variant)
+        .class_select(Class::Wizard);
+
+    assert!(player.is_proficient(Weapon::Quaterstaff));
+    assert!(!player.is_proficient(Weapon::Mace));
+    assert_eq!(player.weap_category, HashSet::new());
+
+    // Feats grant individual weapons
+    player.feat_use(Feat::WeaponMaster(
+        AP::DEX, Weapon::Rapier, Weapon::Longbow,
+        Weapon::Whip, Weapon::Net
+    ));
+
+    assert!(player.is_proficient(Weapon::Rapier));
+    assert!(player.is_proficient(Weapon::Net));
+    assert_eq!(player.get_attack_bonus(Weapon::Longbow), 2);
+
+    // Categories grant every weapon in them
+    player.class_select(Class::Cleric);
+
+    assert_eq!(player.weap_category, HashSet::from([equipment::WeaponCategory::Simple]));
+    assert!(player.is_proficient(Weapon::Mace));
+    assert!(player.is_proficient(Weapon::Whip));
+    assert!(!player.is_proficient(Weapon::Greatsword));
+
+    player.feat_clear();
+
+    assert!(!player.is_proficient(Weapon::Whip));
+    assert!(player.is_proficient(Weapon::Sling));
+}
+
+#[test]
+fn test_language() {
+    let mut player = Character::build();
+
+    player
+        .race_select(Human::Variant)
+        .background_select(Background::Sage);
+
+    // Secret languages are never chosen by default
+    assert_eq!(
+        player.try_race_use_lang(Language::Druidic).unwrap_err(),
+        CharacterError::LanguageRestricted(Language::Druidic)
+    );
+
+    player
+        .race_use_lang(Language::Sylvan)
+        .background_use_lang(Language::Giant)
+        .background_use_lang(Language::Celestial);
+
+    assert_eq!(player.lang.len(), 4);
+    assert_eq!(player.background_lang_point, 0);
+
+    // Exotic choices are dropped and refunded
+    player.lang_rule_select(vec![LanguageCategory::Standard]);
+
+    assert_eq!(player.lang, HashSet::from([Language::Common, Language::Giant]));
+    assert_eq!(player.lang_point, 1);
+    assert_eq!(player.background_lang_point, 1);
+
+    // Secret languages can't be bought with points
+    player.lang_rule_select(vec![
+        LanguageCategory::Standard,
+        LanguageCategory::Exotic,
+        LanguageCategory::Secret
+    ]);
+
+    assert!(!player.get_lang_rule().contains(&LanguageCategory::Secret));
+    assert_eq!(
+        player.try_background_use_lang(Language::ThievesCant).unwrap_err(),
+        CharacterError::LanguageRestricted(Language::ThievesCant)
+    );
+    assert_eq!(Language::ThievesCant.get_script(), None);
+
+    // but are taught by class
+    player.class_select(Class::Rogue);
+
+    assert!(player.lang.contains(&Language::ThievesCant));
+
+    player.class_select(Class::Druid);
+
+    assert!(player.lang.contains(&Language::Druidic));
+    assert!(!player.lang.contains(&Language::ThievesCant));
+}
+
+#[test]
+fn test_tools() {
+    let mut player = Character::build();
+
+    player
+        .race_select(Gnome::Rock)
+        .class_select(Class::Bard)
+        .background_select(Background::GuildArtisan);
+
+    assert_eq!(player.tools, HashSet::from([Tools::TinkersTools]));
+    assert_eq!(player.get_class_unused_tool(), &3);
+    assert_eq!(player.get_background_unused_tool(), &1);
+
+    // Choices are limited to their category
+    assert_eq!(
+        player.try_class_use_tool(Tools::SmithsTools).unwrap_err(),
+        CharacterError::ToolNotAvailable(Tools::SmithsTools)
+    );
+    assert_eq!(
+        player.try_background_use_tool(Tools::TinkersTools).unwrap_err(),
+        CharacterError::ToolKnown(Tools::TinkersTools)
+    );
+
+    player
+        .class_use_tool(Tools::Lute)
+        .class_use_tool(Tools::Drum)
+        .background_use_tool(Tools::SmithsTools);
+
+    assert_eq!(player.tools.len(), 4);
+    assert_eq!(player.get_class_unused_tool(), &1);
+    assert_eq!(
+        player.try_class_remove_tool(Tools::Viol).unwrap_err(),
+        CharacterError::ToolNotAssigned(Tools::Viol)
+    );
+
+    // Race choice already taken from background is dropped
+    player.race_select(Dwarf::Hill);
+
+    assert_eq!(
+        player.try_race_use_tool(Tools::SmithsTools).unwrap_err(),
+        CharacterError::ToolKnown(Tools::SmithsTools)
+    );
+
+    player
+        .background_clear_tool()
+        .race_use_tool(Tools::SmithsTools);
+
+    assert_eq!(player.race_tool_point, 0);
+    assert_eq!(player.tools, HashSet::from([
+        Tools::SmithsTools,
+        Tools::Lute,
+        Tools::Drum
+    ]));
+
+    // Choices from a previous class are removed
+    player.class_select(Class::Fighter);
+
+    assert_eq!(player.tools, HashSet::from([Tools::SmithsTools]));
+    assert_eq!(ToolCategory::Musical.get_tools().len(), 10);
+}
+
+#[test]
+fn test_race_choice() {
+    struct Mercenary;
+
+    impl Race for Mercenary {
+        type Stat = Stat;
+
+        fn as_string(&self) -> String {
+            "Mercenary".to_string()
+        }
+
+        fn get_stat(&self) -> Stat {
+            Stat {
+                ap: [0;6],
+                lang: vec![Language::Common],
+                weap: vec![],
+                armor: vec![],
+                tools: vec![],
+                skill: vec![],
+                choice: vec![
+                    RaceChoice::Weapon(vec![Weapon::Longsword, Weapon::Shortsword]),
+                    RaceChoice::Weapon(vec![Weapon::Shortsword, Weapon::Scimitar]),
+                    RaceChoice::Language
+                ],
+                feat_point: 0,
+                speed: 30,
+                size: Size::Medium,
+                traits: vec![]
+            }
+        }
+    }
+
+    let mut player = Character::build();
+
+    // Draconic ancestry is picked like any other choice
+    player.race_select(Dragonborn::Basic);
+
+    assert_eq!(player.get_race_unused_trait(), &1);
+    assert!(player.get_all_resistance().is_empty());
+
+    player.race_use_trait(RacialTrait::DraconicAncestryGold);
+
+    assert_eq!(player.get_race_unused_trait(), &0);
+    assert_eq!(player.get_all_resistance(), HashSet::from([DamageType::Fire]));
+    assert_eq!(
+        player.try_race_use_trait(RacialTrait::DraconicAncestrySilver).unwrap_err(),
+        CharacterError::NoTraitPoint
+    );
+
+    // Half-Elf picks two skills of any kind
+    player
+        .race_select(HalfElf::Basic)
+        .race_use_skill(Skill::Arcana)
+        .race_use_skill(Skill::History);
+
+    assert_eq!(player.get_race_unused_skill(), &0);
+    assert_eq!(
+        player.try_race_use_skill(Skill::Nature).unwrap_err(),
+        CharacterError::NoRaceSkillPoint
+    );
+
+    // Dwarf picks one of three artisan tools
+    player.race_select(Dwarf::Hill);
+
+    assert_eq!(
+        player.try_race_use_tool(Tools::Lute).unwrap_err(),
+        CharacterError::ToolNotAvailable(Tools::Lute)
+    );
+
+    player.race_use_tool(Tools::BrewersSupplies);
+
+    assert!(player.tools.contains(&Tools::BrewersSupplies));
+
+    // Any race can offer a weapon choice
+    player.race_select(Mercenary);
+
+    assert_eq!(player.get_race_unused_weap(), &2);
+    assert_eq!(player.lang_point, 1);
+    assert_eq!(
+        player.try_race_use_weap(Weapon::Dagger).unwrap_err(),
+        CharacterError::WeaponNotAvailable(Weapon::Dagger)
+    );
+
+    // Picks are matched to choices as a whole, a shared
+    // option moves to the other choice
+    player
+        .race_use_weap(Weapon::Shortsword)
+        .race_use_weap(Weapon::Longsword);
+
+    assert!(player.weap.contains(&Weapon::Shortsword));
+    assert!(player.weap.contains(&Weapon::Longsword));
+    assert_eq!(player.get_race_unused_weap(), &0);
+    assert_eq!(
+        player.try_race_use_weap(Weapon::Scimitar).unwrap_err(),
+        CharacterError::NoWeaponPoint
+    );
+
+    player
+        .race_remove_weap(Weapon::Longsword)
+        .race_remove_weap(Weapon::Shortsword)
+        .race_use_weap(Weapon::Scimitar);
+
+    assert!(player.try_race_use_weap(Weapon::Shortsword).is_ok());
+    assert_eq!(player.get_race_unused_weap(), &0);
+
+    player.race_clear_weap();
+
+    assert!(!player.weap.contains(&Weapon::Shortsword));
+    assert_eq!(player.get_race_unused_weap(), &2);
+}
+
+#[test]
+fn test_race_ability_grant() {
+    // Custom origin: +2 and +1, stacking or not
+    struct CustomOrigin(bool);
+
+    impl Race for CustomOrigin {
+        type Stat = Stat;
+
+        fn as_string(&self) -> String {
+            format!("CustomOrigin({})", self.0)
+        }
+
+        fn get_stat(&self) -> Stat {
+            let grant = |grant: AbilityGrant| match self.0 {
+                true => grant.stacking(),
+                false => grant
+            };
+            Stat {
+                ap: [0;6],
+                lang: vec![Language::Common],
+                weap: vec![],
+                armor: vec![],
+                tools: vec![],
+                skill: vec![],
+                choice: vec![
+                    RaceChoice::Ability(grant(AbilityGrant::new(2, 1))),
+                    RaceChoice::Ability(grant(AbilityGrant::new(1, 1).only(&[AP::CON, AP::WIS])))
+                ],
+                feat_point: 0,
+                speed: 30,
+                size: Size::Medium,
+                traits: vec![]
+            }
+        }
     }
+
+    let mut player = Character::build();
+
+    // Half-Elf bonus excludes CHA
+    player.race_select(HalfElf::Basic);
+
+    assert_eq!(
+        player.try_race_use_ap(AP::CHA).unwrap_err(),
+        CharacterError::AbilityNotAllowed(AP::CHA)
+    );
+
+    player
+        .race_use_ap(AP::DEX)
+        .race_use_ap(AP::CON);
+
+    assert_eq!(player.get_all_ability_score(), [0,1,1,0,0,2]);
+    assert_eq!(player.race_usable_ap, 0);
+
+    // Bonuses don't stack by default
+    player
+        .race_select(CustomOrigin(false))
+        .race_use_ap(AP::STR);
+
+    assert_eq!(
+        player.try_race_use_ap(AP::STR).unwrap_err(),
+        CharacterError::AbilityAlreadyAssigned(AP::STR)
+    );
+    assert_eq!(
+        player.try_race_use_ap(AP::DEX).unwrap_err(),
+        CharacterError::AbilityNotAllowed(AP::DEX)
+    );
+
+    player.race_use_ap(AP::WIS);
+
+    assert_eq!(player.get_all_ability_score(), [2,0,0,0,1,0]);
+
+    // Removing the +2 frees it for another ability
+    player
+        .race_remove_ap(AP::STR)
+        .race_use_ap(AP::CON);
+
+    assert_eq!(player.get_all_ability_score(), [0,0,2,0,1,0]);
+    assert_eq!(
+        player.try_race_use_ap(AP::INT).unwrap_err(),
+        CharacterError::NoRaceAbilityPoint
+    );
+
+    // Earlier picks move to another bonus when needed
+    player
+        .race_clear_ap()
+        .race_use_ap(AP::CON)
+        .race_use_ap(AP::STR);
+
+    assert_eq!(player.get_all_ability_score(), [2,0,1,0,0,0]);
+
+    // Stacking bonuses can go on the same ability
+    player
+        .race_select(CustomOrigin(true))
+        .race_use_ap(AP::CON)
+        .race_use_ap(AP::CON);
+
+    assert_eq!(player.get_all_ability_score(), [0,0,3,0,0,0]);
+    assert_eq!(player.race_usable_ap, 0);
+}
+
+#[test]
+fn test_signed_ability() {
+    let mut player = Character::build();
+
+    // Penalty can't go below 0 before base scores are assigned
+    player.race_select(Kobold::Basic);
+
+    assert_eq!(player.get_all_ability_score(), [0,2,0,0,0,0]);
+
+    player.ap_point_buy([8,15,14,10,12,8]);
+
+    assert_eq!(player.get_all_ability_score(), [6,17,14,10,12,8]);
+    assert_eq!(player.get_ability_modifier(AP::STR), -2);
+
+    player.race_select(Orc::Basic);
+
+    assert_eq!(player.get_all_ability_score(), [10,15,15,8,12,8]);
+    assert_eq!(player.lang, HashSet::from([Language::Common, Language::Orc]));
+
+    // Final scores are kept between 1 and 30
+    player
+        .ap_adjust(AP::STR, -12)
+        .ap_adjust(AP::CON, 20);
+
+    assert_eq!(player.get_all_ability_score(), [1,15,30,8,12,8]);
+    assert_eq!(player.get_ap_adjustment(), &[-12,0,20,0,0,0]);
+
+    player
+        .ap_adjust(AP::CON, isize::MAX)
+        .ap_adjust(AP::STR, isize::MIN);
+
+    assert_eq!(player.get_all_ability_score(), [1,15,30,8,12,8]);
+    assert_eq!(player.get_ap_adjustment(), &[isize::MIN,0,isize::MAX,0,0,0]);
+
+    player.ap_clear_adjust();
+
+    assert_eq!(player.get_all_ability_score(), [10,15,15,8,12,8]);
+
+    // Maximum of 20 ignores adjustments from items and effects
+    let mut player = Character::build();
+
+    player
+        .race_select(HalfOrc::Basic)
+        .ap_point_buy([15,15,15,8,8,8])
+        .class_select(Class::Fighter)
+        .add_xp(34000);
+    player
+        .asi_use(AbilityImprovement::Single(AP::STR))
+        .ap_adjust(AP::STR, 2);
+
+    assert_eq!(player.get_ability_score(AP::STR), 21);
+    assert!(player.try_asi_use(AbilityImprovement::Split(AP::STR, AP::CON)).is_ok());
+
+    player
+        .ap_clear_adjust()
+        .ap_adjust(AP::STR, -4);
+
+    assert_eq!(player.get_ability_score(AP::STR), 16);
+    assert_eq!(player.try_asi_use(AbilityImprovement::Split(AP::STR, AP::DEX)).unwrap_err(),
+        CharacterError::AbilityScoreMax(AP::STR));
 }
@@ -0,0 +1,330 @@
+use crate::common::profeciency::Tools;
+
+/* ---------
+   | Macro |
+   --------- */
+macro_rules! new_tool {
+    ($($tool:ident {
+        name: $name:expr,
+        category: $category:expr,
+        weight: $weight:expr
+    })*) => {
+        impl Tools {
+            /// Return display name of tool
+            ///
+            /// Example:
+            /// ```
+            /// use role4me_lib::prelude::*;
+            ///
+            /// assert_eq!(Tools::TheivesTools.get_name(), "Thieves' tools");
+            /// ```
+            pub fn get_name(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$tool => $name,
+                    )*
+                }
+            }
+
+            /// Return category tool belongs to
+            ///
+            /// Example:
+            /// ```
+            /// use role4me_lib::ed_5;
+            /// use role4me_lib::prelude::*;
+            ///
+            /// assert_eq!(Tools::Lute.get_category(), ed_5::ToolCategory::Musical);
+            /// ```
+            pub fn get_category(&self) -> ToolCategory {
+                use ToolCategory::*;
+                match self {
+                    $(
+                        Self::$tool => $category,
+                    )*
+                }
+            }
+
+            /// Return weight of tool in pounds
+            ///
+            /// Example:
+            /// ```
+            /// use role4me_lib::prelude::*;
+            ///
+            /// assert_eq!(Tools::SmithsTools.get_weight(), 8.0);
+            /// ```
+            pub fn get_weight(&self) -> f32 {
+                match self {
+                    $(
+                        Self::$tool => $weight,
+                    )*
+                }
+            }
+        }
+    };
+}
+
+/* --------
+   | Enum |
+   -------- */
+/// Group of tools offered together as a choice
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum ToolCategory {
+    /// Artisan's tools
+    Artisan,
+    /// Gaming sets
+    Gaming,
+    /// Musical instruments
+    Musical,
+    /// Kits and other specialist tools
+    Kit,
+    /// Land and water vehicles
+    Vehicle
+}
+
+/// Tool proficiency to choose, one tool per choice
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum ToolChoice {
+    /// Any tool of a category
+    Category(ToolCategory),
+    /// One of the listed tools
+    Among(Vec<Tools>)
+}
+
+impl ToolCategory {
+    /// Return every tool of category
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// assert_eq!(ed_5::ToolCategory::Gaming.get_tools().len(), 4);
+    /// ```
+    pub fn get_tools(&self) -> Vec<Tools> {
+        Tools::get_all()
+            .into_iter()
+            .filter(|tool| tool.get_category() == *self)
+            .collect()
+    }
+}
+
+impl ToolChoice {
+    /// Return every tool that can be chosen
+    ///
+    /// Example:
+    /// ```
+    /// use role4me_lib::ed_5;
+    /// use role4me_lib::prelude::*;
+    ///
+    /// let choice = ed_5::ToolChoice::Among(vec![Tools::Drum, Tools::Flute]);
+    ///
+    /// assert_eq!(choice.get_option(), vec![Tools::Drum, Tools::Flute]);
+    /// ```
+    pub fn get_option(&self) -> Vec<Tools> {
+        match self {
+            Self::Category(category) => category.get_tools(),
+            Self::Among(tools) => tools.clone()
+        }
+    }
+}
+
+/* ---------
+   | Tools |
+   --------- */
+new_tool!(
+    AlchemistsSupplies {
+        name: "Alchemist's supplies",
+        category: Artisan,
+        weight: 8.0
+    }
+    BrewersSupplies {
+        name: "Brewer's supplies",
+        category: Artisan,
+        weight: 9.0
+    }
+    CalligraphersSupplies {
+        name: "Calligrapher's supplies",
+        category: Artisan,
+        weight: 5.0
+    }
+    CarpentersTools {
+        name: "Carpenter's tools",
+        category: Artisan,
+        weight: 6.0
+    }
+    CartographersTools {
+        name: "Cartographer's tools",
+        category: Artisan,
+        weight: 6.0
+    }
+    CobblersTools {
+        name: "Cobbler's tools",
+        category: Artisan,
+        weight: 5.0
+    }
+    CooksUtensils {
+        name: "Cook's utensils",
+        category: Artisan,
+        weight: 8.0
+    }
+    GlassblowersTools {
+        name: "Glassblower's tools",
+        category: Artisan,
+        weight: 5.0
+    }
+    JewelersTools {
+        name: "Jeweler's tools",
+        category: Artisan,
+        weight: 2.0
+    }
+    LeatherworkersTools {
+        name: "Leatherworker's tools",
+        category: Artisan,
+        weight: 5.0
+    }
+    MasonsTools {
+        name: "Mason's tools",
+        category: Artisan,
+        weight: 8.0
+    }
+    PaintersSupplies {
+        name: "Painter's supplies",
+        category: Artisan,
+        weight: 5.0
+    }
+    PottersTools {
+        name: "Potter's tools",
+        category: Artisan,
+        weight: 3.0
+    }
+    SmithsTools {
+        name: "Smith's tools",
+        category: Artisan,
+        weight: 8.0
+    }
+    TinkersTools {
+        name: "Tinker's tools",
+        category: Artisan,
+        weight: 10.0
+    }
+    WeaversTools {
+        name: "Weaver's tools",
+        category: Artisan,
+        weight: 5.0
+    }
+    WoodcarversTools {
+        name: "Woodcarver's tools",
+        category: Artisan,
+        weight: 5.0
+    }
+    DiceSet {
+        name: "Dice set",
+        category: Gaming,
+        weight: 0.0
+    }
+    DragonchessSet {
+        name: "Dragonchess set",
+        category: Gaming,
+        weight: 0.5
+    }
+    PlayingCardSet {
+        name: "Playing card set",
+        category: Gaming,
+        weight: 0.0
+    }
+    ThreeDragonAnteSet {
+        name: "Three-Dragon Ante set",
+        category: Gaming,
+        weight: 0.0
+    }
+    Bagpipes {
+        name: "Bagpipes",
+        category: Musical,
+        weight: 6.0
+    }
+    Drum {
+        name: "Drum",
+        category: Musical,
+        weight: 3.0
+    }
+    Dulcimer {
+        name: "Dulcimer",
+        category: Musical,
+        weight: 10.0
+    }
+    Flute {
+        name: "Flute",
+        category: Musical,
+        weight: 1.0
+    }
+    Lute {
+        name: "Lute",
+        category: Musical,
+        weight: 2.0
+    }
+    Lyre {
+        name: "Lyre",
+        category: Musical,
+        weight: 2.0
+    }
+    Horn {
+        name: "Horn",
+        category: Musical,
+        weight: 2.0
+    }
+    PanFlute {
+        name: "Pan flute",
+        category: Musical,
+        weight: 2.0
+    }
+    Shawm {
+        name: "Shawm",
+        category: Musical,
+        weight: 1.0
+    }
+    Viol {
+        name: "Viol",
+        category: Musical,
+        weight: 1.0
+    }
+    DisguiseKit {
+        name: "Disguise kit",
+        category: Kit,
+        weight: 3.0
+    }
+    ForgeryKit {
+        name: "Forgery kit",
+        category: Kit,
+        weight: 5.0
+    }
+    HerbalismKit {
+        name: "Herbalism kit",
+        category: Kit,
+        weight: 3.0
+    }
+    NavigatorsTools {
+        name: "Navigator's tools",
+        category: Kit,
+        weight: 2.0
+    }
+    PoisonersKit {
+        name: "Poisoner's kit",
+        category: Kit,
+        weight: 2.0
+    }
+    TheivesTools {
+        name: "Thieves' tools",
+        category: Kit,
+        weight: 1.0
+    }
+    VehiclesLand {
+        name: "Vehicles (land)",
+        category: Vehicle,
+        weight: 0.0
+    }
+    VehiclesWater {
+        name: "Vehicles (water)",
+        category: Vehicle,
+        weight: 0.0
+    }
+);
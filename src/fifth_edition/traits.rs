@@ -1,18 +1,353 @@
-#[allow(unused_macros)]
-macro_rules! flags {
-    ($name:ident, $($flag:ident),*) => {
-        pub struct $name {
-            $(
-                $flag: bool,
-            )*
-        }
+use crate::common::{AP, Condition, DamageType};
 
-        let my_flag = $name {
-            $(
-                $flag: false,
-            )*
+/* ---------
+   | Macro |
+   --------- */
+macro_rules! new_trait {
+    ($($trait:ident {
+        name: $name:expr,
+        description: $description:expr,
+        effect: $effect:expr
+    })*) => {
+        #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+        pub enum RacialTrait {
+            $($trait,)*
         }
 
-        my_flag
+        impl RacialTrait {
+            /// Return display name of trait
+            pub fn get_name(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$trait => $name,
+                    )*
+                }
+            }
+
+            /// Return rule text summary of trait
+            pub fn get_description(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$trait => $description,
+                    )*
+                }
+            }
+
+            /// Return mechanical effects of trait
+            pub fn get_effect(&self) -> Vec<TraitEffect> {
+                use TraitEffect::*;
+                match self {
+                    $(
+                        Self::$trait => $effect,
+                    )*
+                }
+            }
+        }
     };
 }
+
+/* --------
+   | Enum |
+   -------- */
+/// Mechanical effect of a racial trait
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum TraitEffect {
+    /// Darkvision range in feet
+    Darkvision(usize),
+    /// Swimming speed in feet
+    SwimSpeed(usize),
+    /// Resistance to a damage type
+    Resistance(DamageType),
+    /// Advantage on saving throws against a condition
+    Advantage(Condition),
+    /// Advantage on saving throws of an ability against magic
+    MagicAdvantage(AP),
+    /// Magic can't put you to sleep
+    SleepImmunity,
+    /// Disadvantage on attack rolls and sight based
+    /// Perception checks in direct sunlight
+    SunlightSensitivity,
+    /// Extra hit points gained every level
+    HitPointPerLevel(usize),
+    /// Spell castable from given character level
+    InnateSpell { spell: &'static str, level: usize },
+    /// Breath weapon dealing a damage type
+    BreathWeapon(DamageType),
+    /// Speed is not reduced by wearing heavy armor
+    HeavyArmorSpeed,
+    /// Count as one size larger when carrying, pushing,
+    /// dragging or lifting
+    CarryingSize,
+}
+
+/* ----------
+   | Traits |
+   ---------- */
+new_trait!(
+    Darkvision {
+        name: "Darkvision",
+        description: "See in dim light within 60 feet as if it were bright light, and in darkness as if it were dim light.",
+        effect: vec![Darkvision(60)]
+    }
+    SuperiorDarkvision {
+        name: "Superior Darkvision",
+        description: "See in dim light within 120 feet as if it were bright light, and in darkness as if it were dim light.",
+        effect: vec![Darkvision(120)]
+    }
+    KeenSenses {
+        name: "Keen Senses",
+        description: "Proficiency in the Perception skill.",
+        effect: vec![]
+    }
+    FeyAncestry {
+        name: "Fey Ancestry",
+        description: "Advantage on saving throws against being charmed, and magic can't put you to sleep.",
+        effect: vec![Advantage(Condition::Charmed), SleepImmunity]
+    }
+    Trance {
+        name: "Trance",
+        description: "Meditate deeply for 4 hours a day instead of sleeping.",
+        effect: vec![]
+    }
+    SunlightSensitivity {
+        name: "Sunlight Sensitivity",
+        description: "Disadvantage on attack rolls and Perception checks relying on sight while in direct sunlight.",
+        effect: vec![SunlightSensitivity]
+    }
+    DrowMagic {
+        name: "Drow Magic",
+        description: "Know the dancing lights cantrip, cast faerie fire from 3rd level and darkness from 5th level once per long rest.",
+        effect: vec![
+            InnateSpell { spell: "Dancing Lights", level: 1 },
+            InnateSpell { spell: "Faerie Fire", level: 3 },
+            InnateSpell { spell: "Darkness", level: 5 }
+        ]
+    }
+    ElfWeaponTraining {
+        name: "Elf Weapon Training",
+        description: "Proficiency with the longsword, shortsword, shortbow and longbow.",
+        effect: vec![]
+    }
+    Cantrip {
+        name: "Cantrip",
+        description: "Know one cantrip of your choice from the wizard spell list.",
+        effect: vec![]
+    }
+    MaskOfTheWild {
+        name: "Mask of the Wild",
+        description: "Attempt to hide when only lightly obscured by foliage, heavy rain, falling snow, mist and other natural phenomena.",
+        effect: vec![]
+    }
+    FleetOfFoot {
+        name: "Fleet of Foot",
+        description: "Base walking speed increases to 35 feet.",
+        effect: vec![]
+    }
+    ChildOfTheSea {
+        name: "Child of the Sea",
+        description: "Swimming speed of 30 feet, and breathe air and water.",
+        effect: vec![SwimSpeed(30)]
+    }
+    FriendOfTheSea {
+        name: "Friend of the Sea",
+        description: "Communicate simple ideas with any beast that has an innate swimming speed.",
+        effect: vec![]
+    }
+    DwarvenResilience {
+        name: "Dwarven Resilience",
+        description: "Advantage on saving throws against poison, and resistance against poison damage.",
+        effect: vec![Advantage(Condition::Poisoned), Resistance(DamageType::Poison)]
+    }
+    DwarvenSpeed {
+        name: "Speed",
+        description: "Speed is not reduced by wearing heavy armor.",
+        effect: vec![HeavyArmorSpeed]
+    }
+    DwarvenCombatTraining {
+        name: "Dwarven Combat Training",
+        description: "Proficiency with the battleaxe, handaxe, light hammer and warhammer.",
+        effect: vec![]
+    }
+    Stonecunning {
+        name: "Stonecunning",
+        description: "Double proficiency bonus on History checks related to the origin of stonework.",
+        effect: vec![]
+    }
+    DwarvenToughness {
+        name: "Dwarven Toughness",
+        description: "Hit point maximum increases by 1, and increases by 1 every time you gain a level.",
+        effect: vec![HitPointPerLevel(1)]
+    }
+    DwarvenArmorTraining {
+        name: "Dwarven Armor Training",
+        description: "Proficiency with light and medium armor.",
+        effect: vec![]
+    }
+    DuergarResilience {
+        name: "Duergar Resilience",
+        description: "Advantage on saving throws against illusions and against being charmed or paralyzed.",
+        effect: vec![Advantage(Condition::Charmed), Advantage(Condition::Paralyzed)]
+    }
+    DuergarMagic {
+        name: "Duergar Magic",
+        description: "Cast enlarge/reduce (enlarge only) from 3rd level and invisibility from 5th level on yourself once per long rest.",
+        effect: vec![
+            InnateSpell { spell: "Enlarge/Reduce", level: 3 },
+            InnateSpell { spell: "Invisibility", level: 5 }
+        ]
+    }
+    Lucky {
+        name: "Lucky",
+        description: "Reroll a 1 on an attack roll, ability check or saving throw, and use the new roll.",
+        effect: vec![]
+    }
+    Brave {
+        name: "Brave",
+        description: "Advantage on saving throws against being frightened.",
+        effect: vec![Advantage(Condition::Frightened)]
+    }
+    HalflingNimbleness {
+        name: "Halfling Nimbleness",
+        description: "Move through the space of any creature that is of a size larger than yours.",
+        effect: vec![]
+    }
+    NaturallyStealthy {
+        name: "Naturally Stealthy",
+        description: "Attempt to hide even when obscured only by a creature that is at least one size larger than you.",
+        effect: vec![]
+    }
+    StoutResilience {
+        name: "Stout Resilience",
+        description: "Advantage on saving throws against poison, and resistance against poison damage.",
+        effect: vec![Advantage(Condition::Poisoned), Resistance(DamageType::Poison)]
+    }
+    GnomeCunning {
+        name: "Gnome Cunning",
+        description: "Advantage on all Intelligence, Wisdom and Charisma saving throws against magic.",
+        effect: vec![MagicAdvantage(AP::INT), MagicAdvantage(AP::WIS), MagicAdvantage(AP::CHA)]
+    }
+    NaturalIllusionist {
+        name: "Natural Illusionist",
+        description: "Know the minor illusion cantrip.",
+        effect: vec![InnateSpell { spell: "Minor Illusion", level: 1 }]
+    }
+    SpeakWithSmallBeasts {
+        name: "Speak with Small Beasts",
+        description: "Communicate simple ideas with Small or smaller beasts.",
+        effect: vec![]
+    }
+    ArtificersLore {
+        name: "Artificer's Lore",
+        description: "Add twice proficiency bonus to History checks related to magic items, alchemical objects or technological devices.",
+        effect: vec![]
+    }
+    Tinker {
+        name: "Tinker",
+        description: "Proficiency with tinker's tools, and construct tiny clockwork devices.",
+        effect: vec![]
+    }
+    SkillVersatility {
+        name: "Skill Versatility",
+        description: "Proficiency in two skills of your choice.",
+        effect: vec![]
+    }
+    Menacing {
+        name: "Menacing",
+        description: "Proficiency in the Intimidation skill.",
+        effect: vec![]
+    }
+    RelentlessEndurance {
+        name: "Relentless Endurance",
+        description: "Drop to 1 hit point instead of 0 once per long rest.",
+        effect: vec![]
+    }
+    SavageAttacks {
+        name: "Savage Attacks",
+        description: "Roll one of the weapon's damage dice one additional time on a melee critical hit.",
+        effect: vec![]
+    }
+    HellishResistance {
+        name: "Hellish Resistance",
+        description: "Resistance to fire damage.",
+        effect: vec![Resistance(DamageType::Fire)]
+    }
+    InfernalLegacy {
+        name: "Infernal Legacy",
+        description: "Know the thaumaturgy cantrip, cast hellish rebuke from 3rd level and darkness from 5th level once per long rest.",
+        effect: vec![
+            InnateSpell { spell: "Thaumaturgy", level: 1 },
+            InnateSpell { spell: "Hellish Rebuke", level: 3 },
+            InnateSpell { spell: "Darkness", level: 5 }
+        ]
+    }
+    DraconicAncestryBlack {
+        name: "Draconic Ancestry (Black)",
+        description: "Breath weapon and resistance to acid damage.",
+        effect: vec![BreathWeapon(DamageType::Acid), Resistance(DamageType::Acid)]
+    }
+    DraconicAncestryBlue {
+        name: "Draconic Ancestry (Blue)",
+        description: "Breath weapon and resistance to lightning damage.",
+        effect: vec![BreathWeapon(DamageType::Lightning), Resistance(DamageType::Lightning)]
+    }
+    DraconicAncestryBrass {
+        name: "Draconic Ancestry (Brass)",
+        description: "Breath weapon and resistance to fire damage.",
+        effect: vec![BreathWeapon(DamageType::Fire), Resistance(DamageType::Fire)]
+    }
+    DraconicAncestryBronze {
+        name: "Draconic Ancestry (Bronze)",
+        description: "Breath weapon and resistance to lightning damage.",
+        effect: vec![BreathWeapon(DamageType::Lightning), Resistance(DamageType::Lightning)]
+    }
+    DraconicAncestryCopper {
+        name: "Draconic Ancestry (Copper)",
+        description: "Breath weapon and resistance to acid damage.",
+        effect: vec![BreathWeapon(DamageType::Acid), Resistance(DamageType::Acid)]
+    }
+    DraconicAncestryGold {
+        name: "Draconic Ancestry (Gold)",
+        description: "Breath weapon and resistance to fire damage.",
+        effect: vec![BreathWeapon(DamageType::Fire), Resistance(DamageType::Fire)]
+    }
+    DraconicAncestryGreen {
+        name: "Draconic Ancestry (Green)",
+        description: "Breath weapon and resistance to poison damage.",
+        effect: vec![BreathWeapon(DamageType::Poison), Resistance(DamageType::Poison)]
+    }
+    DraconicAncestryRed {
+        name: "Draconic Ancestry (Red)",
+        description: "Breath weapon and resistance to fire damage.",
+        effect: vec![BreathWeapon(DamageType::Fire), Resistance(DamageType::Fire)]
+    }
+    DraconicAncestrySilver {
+        name: "Draconic Ancestry (Silver)",
+        description: "Breath weapon and resistance to cold damage.",
+        effect: vec![BreathWeapon(DamageType::Cold), Resistance(DamageType::Cold)]
+    }
+    DraconicAncestryWhite {
+        name: "Draconic Ancestry (White)",
+        description: "Breath weapon and resistance to cold damage.",
+        effect: vec![BreathWeapon(DamageType::Cold), Resistance(DamageType::Cold)]
+    }
+    GrovelCowerAndBeg {
+        name: "Grovel, Cower, and Beg",
+        description: "Cower pathetically to grant allies advantage on attack rolls against nearby enemies once per short rest.",
+        effect: vec![]
+    }
+    PackTactics {
+        name: "Pack Tactics",
+        description: "Advantage on attack rolls against a creature with an ally within 5 feet of it.",
+        effect: vec![]
+    }
+    Aggressive {
+        name: "Aggressive",
+        description: "Move up to your speed toward a hostile creature as a bonus action.",
+        effect: vec![]
+    }
+    PowerfulBuild {
+        name: "Powerful Build",
+        description: "Count as one size larger for carrying capacity and the weight you can push, drag or lift.",
+        effect: vec![CarryingSize]
+    }
+);
@@ -2,12 +2,22 @@ mod fifth_edition;
 mod common;
 
 pub mod prelude {
-    pub use crate::common::{AP, Size};
+    pub use crate::common::{AP, Size, DamageType, Condition};
     pub use crate::common::profeciency::{Language, Weapon, Skill, Armor, Tools};
 }
 
 pub mod ed_5 {
-    pub use crate::fifth_edition::Character;
+    pub use crate::fifth_edition::{Character, HitPointMethod, AbilityImprovement};
     pub use crate::fifth_edition::race::*;
     pub use crate::fifth_edition::class::Class;
+    pub use crate::fifth_edition::error::CharacterError;
+    pub use crate::fifth_edition::traits::{RacialTrait, TraitEffect};
+    pub use crate::fifth_edition::language::{LanguageCategory, Script};
+    pub use crate::fifth_edition::tool::{ToolCategory, ToolChoice};
+    pub use crate::fifth_edition::feat::{Feat, FeatEffect, FeatPrerequisite};
+    pub use crate::fifth_edition::equipment::{
+        Item, ItemCategory, ArmorItem, ArmorPenalty, DexBonus, Gear, Encumbrance, EncumbranceRule,
+        WeaponStat, WeaponCategory, WeaponProf, WeaponRange, WeaponProperty
+    };
+    pub use crate::fifth_edition::background::{Background, BackgroundStat, CustomBackground, Feature};
 }